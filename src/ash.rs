@@ -0,0 +1,169 @@
+use crate::{error::FormatConversionError, Format, FormatAspects, Header, Reader};
+use ::ash::vk;
+use core::convert::TryFrom;
+
+impl From<Format> for vk::Format {
+    fn from(format: Format) -> Self {
+        vk::Format::from_raw(format.0.get() as i32)
+    }
+}
+
+/// Fails only for `UNDEFINED` and negative values, which aren't valid formats.
+impl TryFrom<vk::Format> for Format {
+    type Error = FormatConversionError;
+
+    fn try_from(format: vk::Format) -> Result<Self, Self::Error> {
+        u32::try_from(format.as_raw())
+            .ok()
+            .and_then(Format::new)
+            .ok_or(FormatConversionError::Unsupported)
+    }
+}
+
+impl Header {
+    /// Create info of a sampled, optimally tiled image holding every level of this texture, with the usages
+    /// needed to upload it.
+    ///
+    /// Cubemaps get six array layers per layer and the `CUBE_COMPATIBLE` flag. Fails with
+    /// [`FormatConversionError::Undefined`] for textures without a format, such as Basis Universal textures,
+    /// which must be transcoded first, and with [`FormatConversionError::LayerCountOverflow`] if the layers and
+    /// faces don't fit in a `u32`.
+    pub fn vk_image_create_info(&self) -> Result<vk::ImageCreateInfo<'static>, FormatConversionError> {
+        let format = self.format.ok_or(FormatConversionError::Undefined)?;
+        let array_layers = array_layer_count(self).ok_or(FormatConversionError::LayerCountOverflow)?;
+        let image_type = if self.pixel_depth > 0 {
+            vk::ImageType::TYPE_3D
+        } else if self.pixel_height > 0 {
+            vk::ImageType::TYPE_2D
+        } else {
+            vk::ImageType::TYPE_1D
+        };
+        let flags = if self.face_count == 6 {
+            vk::ImageCreateFlags::CUBE_COMPATIBLE
+        } else {
+            vk::ImageCreateFlags::empty()
+        };
+
+        Ok(vk::ImageCreateInfo::default()
+            .flags(flags)
+            .image_type(image_type)
+            .format(format.into())
+            .extent(level_extent(self, 0))
+            .mip_levels(self.level_count.max(1))
+            .array_layers(array_layers)
+            .samples(vk::SampleCountFlags::TYPE_1)
+            .tiling(vk::ImageTiling::OPTIMAL)
+            .usage(vk::ImageUsageFlags::SAMPLED | vk::ImageUsageFlags::TRANSFER_DST)
+            .sharing_mode(vk::SharingMode::EXCLUSIVE)
+            .initial_layout(vk::ImageLayout::UNDEFINED))
+    }
+}
+
+impl<Data: AsRef<[u8]>> Reader<Data> {
+    /// Regions copying every level into an image created from [`Header::vk_image_create_info`], with one region
+    /// per level covering all of its layers and faces.
+    ///
+    /// Regions expect the file to be copied into the buffer at `buffer_offset`, so that levels keep their
+    /// offsets. Returns `None` for supercompressed textures, for formats with both depth and stencil
+    /// aspects, which Vulkan copies from separate buffer regions, and if the layers and faces don't fit in a
+    /// `u32` or the file doesn't fit in the buffer's address range.
+    pub fn vk_buffer_image_copies(
+        &self,
+        buffer_offset: vk::DeviceSize,
+    ) -> Option<impl ExactSizeIterator<Item = vk::BufferImageCopy> + '_> {
+        let header = self.header();
+        if header.supercompression_scheme.is_some() {
+            return None;
+        }
+        let aspects = header.format.map_or(FormatAspects::COLOR, Format::aspects);
+        let aspect_mask = if aspects == FormatAspects::DEPTH {
+            vk::ImageAspectFlags::DEPTH
+        } else if aspects == FormatAspects::STENCIL {
+            vk::ImageAspectFlags::STENCIL
+        } else if aspects.contains(FormatAspects::DEPTH) {
+            return None;
+        } else {
+            vk::ImageAspectFlags::COLOR
+        };
+
+        let layer_count = array_layer_count(&header)?;
+        buffer_offset.checked_add(self.data().len() as u64)?;
+        let levels = self.level_index().ok()?;
+        Some(levels.enumerate().map(move |(level, index)| vk::BufferImageCopy {
+            buffer_offset: buffer_offset + index.byte_offset,
+            buffer_row_length: 0,
+            buffer_image_height: 0,
+            image_subresource: vk::ImageSubresourceLayers {
+                aspect_mask,
+                mip_level: level as u32,
+                base_array_layer: 0,
+                layer_count,
+            },
+            image_offset: vk::Offset3D::default(),
+            image_extent: level_extent(&header, level as u32),
+        }))
+    }
+}
+
+fn array_layer_count(header: &Header) -> Option<u32> {
+    header.layer_count.max(1).checked_mul(header.face_count)
+}
+
+fn level_extent(header: &Header, level: u32) -> vk::Extent3D {
+    let extent = |size: u32| size.checked_shr(level).unwrap_or(0).max(1);
+    vk::Extent3D {
+        width: extent(header.pixel_width),
+        height: extent(header.pixel_height),
+        depth: extent(header.pixel_depth),
+    }
+}
+
+#[test]
+fn test_vk_image() {
+    let file = crate::test_util::TEST_TEX;
+    let reader = Reader::new(file).unwrap();
+
+    for &format in Format::ALL {
+        assert_eq!(Format::try_from(vk::Format::from(format)), Ok(format));
+    }
+    assert!(vk::Format::from(Format::BC7_SRGB_BLOCK) == vk::Format::BC7_SRGB_BLOCK);
+    assert_eq!(
+        Format::try_from(vk::Format::UNDEFINED),
+        Err(FormatConversionError::Unsupported)
+    );
+
+    let info = reader.header().vk_image_create_info().unwrap();
+    assert!(info.format == vk::Format::R8G8B8A8_UINT && info.image_type == vk::ImageType::TYPE_2D);
+    assert_eq!(
+        (info.extent.width, info.extent.height, info.extent.depth),
+        (1024, 512, 1)
+    );
+    assert_eq!((info.mip_levels, info.array_layers), (11, 1));
+
+    let copies = reader.vk_buffer_image_copies(16).unwrap();
+    assert_eq!(copies.len(), 11);
+    for (copy, level) in copies.zip(reader.levels()) {
+        let start = (copy.buffer_offset - 16) as usize;
+        assert_eq!(&file[start..start + level.data.len()], level.data);
+        let extent = copy.image_extent;
+        assert_eq!(level.data.len() as u32, extent.width * extent.height * 4);
+    }
+}
+
+#[test]
+fn test_vk_layer_count_overflow() {
+    let mut file = crate::test_util::TEST_TEX.to_vec();
+    // 2^31 layers of six faces
+    file[32..36].copy_from_slice(&(1u32 << 31).to_le_bytes());
+    file[36..40].copy_from_slice(&6u32.to_le_bytes());
+    let reader = Reader::new(&file[..]).unwrap();
+
+    assert_eq!(
+        reader.header().vk_image_create_info().err(),
+        Some(FormatConversionError::LayerCountOverflow)
+    );
+    assert!(reader.vk_buffer_image_copies(0).is_none());
+
+    let reader = Reader::new(crate::test_util::TEST_TEX).unwrap();
+    assert!(reader.vk_buffer_image_copies(u64::MAX).is_none());
+}
@@ -0,0 +1,276 @@
+use crate::{error::ParseError, Header, Reader, SupercompressionScheme};
+use core::convert::TryInto;
+
+/// Global header of the [BasisLZ](https://github.khronos.org/KTX-Specification/#basislz_gd) supercompression global data.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BasisLZGlobalHeader {
+    pub endpoint_count: u16,
+    pub selector_count: u16,
+    pub endpoints_byte_length: u32,
+    pub selectors_byte_length: u32,
+    pub tables_byte_length: u32,
+    pub extended_byte_length: u32,
+}
+
+impl BasisLZGlobalHeader {
+    pub const LENGTH: usize = 20;
+
+    pub fn from_bytes(data: &[u8; Self::LENGTH]) -> Self {
+        Self {
+            endpoint_count: u16::from_le_bytes(data[0..2].try_into().unwrap()),
+            selector_count: u16::from_le_bytes(data[2..4].try_into().unwrap()),
+            endpoints_byte_length: u32::from_le_bytes(data[4..8].try_into().unwrap()),
+            selectors_byte_length: u32::from_le_bytes(data[8..12].try_into().unwrap()),
+            tables_byte_length: u32::from_le_bytes(data[12..16].try_into().unwrap()),
+            extended_byte_length: u32::from_le_bytes(data[16..20].try_into().unwrap()),
+        }
+    }
+
+    pub fn as_bytes(&self) -> [u8; Self::LENGTH] {
+        let mut bytes = [0; Self::LENGTH];
+
+        bytes[0..2].copy_from_slice(&self.endpoint_count.to_le_bytes()[..]);
+        bytes[2..4].copy_from_slice(&self.selector_count.to_le_bytes()[..]);
+        bytes[4..8].copy_from_slice(&self.endpoints_byte_length.to_le_bytes()[..]);
+        bytes[8..12].copy_from_slice(&self.selectors_byte_length.to_le_bytes()[..]);
+        bytes[12..16].copy_from_slice(&self.tables_byte_length.to_le_bytes()[..]);
+        bytes[16..20].copy_from_slice(&self.extended_byte_length.to_le_bytes()[..]);
+
+        bytes
+    }
+}
+
+bitflags::bitflags! {
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct BasisLZImageFlags: u32 {
+        const IS_P_FRAME = (1 << 1);
+    }
+}
+
+/// Location of one image's slices within its level's data.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BasisLZImageDescription {
+    pub image_flags: BasisLZImageFlags,
+    pub rgb_slice_byte_offset: u32,
+    pub rgb_slice_byte_length: u32,
+    pub alpha_slice_byte_offset: u32,
+    pub alpha_slice_byte_length: u32,
+}
+
+impl BasisLZImageDescription {
+    pub const LENGTH: usize = 20;
+
+    pub fn from_bytes(data: &[u8; Self::LENGTH]) -> Self {
+        Self {
+            image_flags: BasisLZImageFlags::from_bits_retain(u32::from_le_bytes(data[0..4].try_into().unwrap())),
+            rgb_slice_byte_offset: u32::from_le_bytes(data[4..8].try_into().unwrap()),
+            rgb_slice_byte_length: u32::from_le_bytes(data[8..12].try_into().unwrap()),
+            alpha_slice_byte_offset: u32::from_le_bytes(data[12..16].try_into().unwrap()),
+            alpha_slice_byte_length: u32::from_le_bytes(data[16..20].try_into().unwrap()),
+        }
+    }
+
+    pub fn as_bytes(&self) -> [u8; Self::LENGTH] {
+        let mut bytes = [0; Self::LENGTH];
+
+        bytes[0..4].copy_from_slice(&self.image_flags.bits().to_le_bytes()[..]);
+        bytes[4..8].copy_from_slice(&self.rgb_slice_byte_offset.to_le_bytes()[..]);
+        bytes[8..12].copy_from_slice(&self.rgb_slice_byte_length.to_le_bytes()[..]);
+        bytes[12..16].copy_from_slice(&self.alpha_slice_byte_offset.to_le_bytes()[..]);
+        bytes[16..20].copy_from_slice(&self.alpha_slice_byte_length.to_le_bytes()[..]);
+
+        bytes
+    }
+}
+
+/// Parsed BasisLZ supercompression global data.
+///
+/// Image descriptions are ordered by level, then layer, then face, then z slice.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BasisLZGlobalData<'data> {
+    pub header: BasisLZGlobalHeader,
+    image_descriptions: &'data [u8],
+    endpoints: &'data [u8],
+    selectors: &'data [u8],
+    tables: &'data [u8],
+    extended: &'data [u8],
+}
+
+impl<'data> BasisLZGlobalData<'data> {
+    /// Parse the global data of a texture containing `image_count` images.
+    ///
+    /// Fails with [`ParseError::UnexpectedEnd`] if a section lies outside `data`,
+    /// and with [`ParseError::InvalidSupercompressionGlobalData`] if bytes are left over.
+    pub fn parse(data: &'data [u8], image_count: usize) -> Result<Self, ParseError> {
+        let header_data = data
+            .get(..BasisLZGlobalHeader::LENGTH)
+            .ok_or(ParseError::UnexpectedEnd)?;
+        let header = BasisLZGlobalHeader::from_bytes(header_data.try_into().unwrap());
+
+        let mut offset = BasisLZGlobalHeader::LENGTH;
+        let mut section = |length: usize| -> Result<&'data [u8], ParseError> {
+            let end = offset.checked_add(length).ok_or(ParseError::UnexpectedEnd)?;
+            let section = data.get(offset..end).ok_or(ParseError::UnexpectedEnd)?;
+            offset = end;
+            Ok(section)
+        };
+
+        let image_descriptions = section(
+            image_count
+                .checked_mul(BasisLZImageDescription::LENGTH)
+                .ok_or(ParseError::UnexpectedEnd)?,
+        )?;
+        let endpoints = section(header.endpoints_byte_length as usize)?;
+        let selectors = section(header.selectors_byte_length as usize)?;
+        let tables = section(header.tables_byte_length as usize)?;
+        let extended = section(header.extended_byte_length as usize)?;
+
+        if offset != data.len() {
+            return Err(ParseError::InvalidSupercompressionGlobalData);
+        }
+
+        Ok(Self {
+            header,
+            image_descriptions,
+            endpoints,
+            selectors,
+            tables,
+            extended,
+        })
+    }
+
+    pub fn image_descriptions(&self) -> impl ExactSizeIterator<Item = BasisLZImageDescription> + 'data {
+        self.image_descriptions
+            .chunks_exact(BasisLZImageDescription::LENGTH)
+            .map(|data| BasisLZImageDescription::from_bytes(data.try_into().unwrap()))
+    }
+
+    /// Endpoint codebook
+    pub fn endpoints(&self) -> &'data [u8] {
+        self.endpoints
+    }
+
+    /// Selector codebook
+    pub fn selectors(&self) -> &'data [u8] {
+        self.selectors
+    }
+
+    /// Huffman tables for slice decoding
+    pub fn tables(&self) -> &'data [u8] {
+        self.tables
+    }
+
+    pub fn extended(&self) -> &'data [u8] {
+        self.extended
+    }
+}
+
+impl<Data: AsRef<[u8]>> Reader<Data> {
+    /// Parsed supercompression global data, or `None` if the texture isn't BasisLZ supercompressed.
+    pub fn basis_lz_global_data(&self) -> Option<Result<BasisLZGlobalData<'_>, ParseError>> {
+        let header = self.header();
+        if header.supercompression_scheme != Some(SupercompressionScheme::BasisLZ) {
+            return None;
+        }
+        Some(
+            image_count(&header)
+                .and_then(|image_count| BasisLZGlobalData::parse(self.supercompression_global_data(), image_count)),
+        )
+    }
+}
+
+/// Number of images in the texture: one per level, layer, face and z slice.
+///
+/// Fails with [`ParseError::UnexpectedEnd`] if the count doesn't fit in a `usize`, as no global data could hold
+/// that many image descriptions.
+pub(crate) fn image_count(header: &Header) -> Result<usize, ParseError> {
+    let layers = header.layer_count.max(1) as usize;
+    let faces = header.face_count as usize;
+    (0..header.level_count.max(1)).try_fold(0usize, |count, level| {
+        let depth = header.pixel_depth.checked_shr(level).unwrap_or(0).max(1) as usize;
+        layers
+            .checked_mul(faces)
+            .and_then(|images| images.checked_mul(depth))
+            .and_then(|images| count.checked_add(images))
+            .ok_or(ParseError::UnexpectedEnd)
+    })
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_basis_lz_global_data() {
+    use crate::{
+        test_util::{test_tex_dfd, writer_header, TEST_TEX},
+        Level, Writer, WriterHeader,
+    };
+    use alloc::vec::Vec;
+
+    let global_header = BasisLZGlobalHeader {
+        endpoint_count: 1,
+        selector_count: 2,
+        endpoints_byte_length: 3,
+        selectors_byte_length: 4,
+        tables_byte_length: 5,
+        extended_byte_length: 0,
+    };
+    let descriptions = [4, 2].map(|length| BasisLZImageDescription {
+        image_flags: BasisLZImageFlags::empty(),
+        rgb_slice_byte_offset: 0,
+        rgb_slice_byte_length: length,
+        alpha_slice_byte_offset: 0,
+        alpha_slice_byte_length: 0,
+    });
+    let mut sgd = Vec::new();
+    sgd.extend_from_slice(&global_header.as_bytes());
+    for description in &descriptions {
+        sgd.extend_from_slice(&description.as_bytes());
+    }
+    sgd.extend_from_slice(&[1; 3]);
+    sgd.extend_from_slice(&[2; 4]);
+    sgd.extend_from_slice(&[3; 5]);
+
+    let mut writer = Writer::new(WriterHeader {
+        level_count: 2,
+        supercompression_scheme: Some(SupercompressionScheme::BasisLZ),
+        ..writer_header(None, 2)
+    });
+    writer
+        .data_format_descriptors(test_tex_dfd())
+        .supercompression_global_data(&sgd);
+    for length in [4, 2] {
+        writer.level(Level {
+            data: &[0; 4][..length],
+            uncompressed_byte_length: 0,
+        });
+    }
+    let bytes = writer.write().unwrap();
+
+    let reader = Reader::new(&bytes[..]).unwrap();
+    let global_data = reader.basis_lz_global_data().unwrap().unwrap();
+    assert_eq!(global_data.header, global_header);
+    assert_eq!(global_data.image_descriptions().collect::<Vec<_>>(), descriptions);
+    assert_eq!(global_data.endpoints(), &[1; 3]);
+    assert_eq!(global_data.selectors(), &[2; 4]);
+    assert_eq!(global_data.tables(), &[3; 5]);
+    assert!(global_data.extended().is_empty());
+
+    assert!(matches!(
+        BasisLZGlobalData::parse(&sgd[..sgd.len() - 1], 2),
+        Err(ParseError::UnexpectedEnd)
+    ));
+    assert!(matches!(
+        BasisLZGlobalData::parse(&sgd, 1),
+        Err(ParseError::InvalidSupercompressionGlobalData)
+    ));
+    assert!(Reader::new(TEST_TEX).unwrap().basis_lz_global_data().is_none());
+
+    // Image counts that overflow are rejected
+    let mut bytes = bytes;
+    bytes[28..40].copy_from_slice(&[0xFF; 12]);
+    let reader = Reader::new(&bytes[..]).unwrap();
+    assert!(matches!(
+        reader.basis_lz_global_data(),
+        Some(Err(ParseError::UnexpectedEnd))
+    ));
+}
@@ -0,0 +1,683 @@
+use crate::{
+    error::{FormatInferenceError, WriteError},
+    format::Suffix,
+    BasicDataFormatDescriptor, BasicDataFormatDescriptorHeader, ChannelTypeQualifiers, ColorModel, ColorPrimaries,
+    DataFormatDescriptorHeader, DataFormatFlags, Format, SampleInformation, TransferFunction,
+};
+use alloc::vec::Vec;
+
+/// Builds a basic data format descriptor block.
+///
+/// ```rust
+/// use ktx2::{BasicDataFormatDescriptor, BasicDataFormatDescriptorBuilder, ChannelTypeQualifiers, ColorModel, SampleInformation};
+///
+/// let block = BasicDataFormatDescriptorBuilder::new()
+///     .color_model(Some(ColorModel::RGBSDA))
+///     .bytes_planes([1, 0, 0, 0, 0, 0, 0, 0])
+///     .sample(SampleInformation {
+///         bit_offset: 0,
+///         bit_length: 8,
+///         channel_type: 0,
+///         channel_type_qualifiers: ChannelTypeQualifiers::empty(),
+///         sample_positions: [0; 4],
+///         lower: 0,
+///         upper: 255,
+///     })
+///     .build()
+///     .unwrap();
+///
+/// // Skip the descriptor block header
+/// let dfd = BasicDataFormatDescriptor::parse(&block[8..]).unwrap();
+/// assert_eq!(dfd.header.color_model, Some(ColorModel::RGBSDA));
+/// assert_eq!(dfd.sample_information().count(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BasicDataFormatDescriptorBuilder {
+    header: BasicDataFormatDescriptorHeader,
+    samples: Vec<SampleInformation>,
+}
+
+impl BasicDataFormatDescriptorBuilder {
+    /// Create a builder with an unspecified color model, primaries and transfer function,
+    /// 1x1x1x1 texel blocks and no samples.
+    pub fn new() -> Self {
+        Self {
+            header: BasicDataFormatDescriptorHeader {
+                color_model: None,
+                color_primaries: None,
+                transfer_function: None,
+                flags: DataFormatFlags::STRAIGHT_ALPHA,
+                texel_block_dimensions: [1; 4],
+                bytes_planes: [0; 8],
+            },
+            samples: Vec::new(),
+        }
+    }
+
+    /// Create a builder holding the canonical descriptor the KTX specification mandates for `format`.
+    ///
+    /// The result matches the descriptors emitted by the Khronos dfdutils. Returns `None` for values
+    /// that aren't one of the known [`Format`] constants.
+    ///
+    /// ```rust
+    /// use ktx2::{BasicDataFormatDescriptor, BasicDataFormatDescriptorBuilder, ColorModel, Format, TransferFunction};
+    ///
+    /// let block = BasicDataFormatDescriptorBuilder::from_format(Format::BC7_SRGB_BLOCK)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// let dfd = BasicDataFormatDescriptor::parse(&block[8..]).unwrap();
+    /// assert_eq!(dfd.header.color_model, Some(ColorModel::BC7));
+    /// assert_eq!(dfd.header.transfer_function, Some(TransferFunction::SRGB));
+    /// assert_eq!(dfd.header.texel_block_dimensions, [4, 4, 1, 1]);
+    /// ```
+    pub fn from_format(format: Format) -> Option<Self> {
+        canonical(format)
+    }
+
+    /// Create a builder starting from an existing header, with no samples.
+    pub fn from_header(header: BasicDataFormatDescriptorHeader) -> Self {
+        Self {
+            header,
+            samples: Vec::new(),
+        }
+    }
+
+    /// None means Unspecified
+    pub fn color_model(&mut self, color_model: Option<ColorModel>) -> &mut Self {
+        self.header.color_model = color_model;
+        self
+    }
+
+    /// None means Unspecified
+    pub fn color_primaries(&mut self, color_primaries: Option<ColorPrimaries>) -> &mut Self {
+        self.header.color_primaries = color_primaries;
+        self
+    }
+
+    /// None means Unspecified
+    pub fn transfer_function(&mut self, transfer_function: Option<TransferFunction>) -> &mut Self {
+        self.header.transfer_function = transfer_function;
+        self
+    }
+
+    pub fn flags(&mut self, flags: DataFormatFlags) -> &mut Self {
+        self.header.flags = flags;
+        self
+    }
+
+    /// Texel block dimensions in texels, each in `1..=256`.
+    pub fn texel_block_dimensions(&mut self, texel_block_dimensions: [u32; 4]) -> &mut Self {
+        self.header.texel_block_dimensions = texel_block_dimensions;
+        self
+    }
+
+    /// Bytes per plane, each in `0..=255`.
+    pub fn bytes_planes(&mut self, bytes_planes: [u32; 8]) -> &mut Self {
+        self.header.bytes_planes = bytes_planes;
+        self
+    }
+
+    /// Append a sample.
+    pub fn sample(&mut self, sample: SampleInformation) -> &mut Self {
+        self.samples.push(sample);
+        self
+    }
+
+    /// Serialize the descriptor block, including its [`DataFormatDescriptorHeader`].
+    pub fn build(&self) -> Result<Vec<u8>, WriteError> {
+        let header = &self.header;
+        let in_range = |value: u32, min: u32, max: u32| (min..=max).contains(&value);
+        let valid_header = header.color_model.is_none_or(|model| model.0.get() <= 0xFF)
+            && header.color_primaries.is_none_or(|primaries| primaries.0.get() <= 0xFF)
+            && header.transfer_function.is_none_or(|transfer| transfer.0.get() <= 0xFF)
+            && header.texel_block_dimensions.iter().all(|&d| in_range(d, 1, 256))
+            && header.bytes_planes.iter().all(|&b| b <= 0xFF);
+        let valid_samples = self.samples.iter().all(|sample| {
+            in_range(sample.bit_offset, 0, 0xFFFF)
+                && in_range(sample.bit_length, 1, 256)
+                && sample.channel_type <= 0xF
+                && sample.sample_positions.iter().all(|&p| p <= 0xFF)
+        });
+        let descriptor_block_size = DataFormatDescriptorHeader::LENGTH
+            + BasicDataFormatDescriptorHeader::LENGTH
+            + self.samples.len() * SampleInformation::LENGTH;
+        if !valid_header || !valid_samples || descriptor_block_size > u16::MAX as usize {
+            return Err(WriteError::InvalidDataFormatDescriptor);
+        }
+
+        let mut bytes = Vec::with_capacity(descriptor_block_size);
+        bytes.extend_from_slice(&DataFormatDescriptorHeader::BASIC.as_bytes(descriptor_block_size as u16));
+        bytes.extend_from_slice(&header.as_bytes());
+        for sample in &self.samples {
+            bytes.extend_from_slice(&sample.as_bytes());
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl Default for BasicDataFormatDescriptorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'data> From<&BasicDataFormatDescriptor<'data>> for BasicDataFormatDescriptorBuilder {
+    fn from(dfd: &BasicDataFormatDescriptor<'data>) -> Self {
+        Self {
+            header: dfd.header,
+            samples: dfd.sample_information().collect(),
+        }
+    }
+}
+
+impl BasicDataFormatDescriptor<'_> {
+    /// Find the [`Format`] whose canonical descriptor matches this one.
+    ///
+    /// Color primaries, alpha premultiplication and sample positions are ignored, as is every transfer
+    /// function other than [`TransferFunction::SRGB`]. Sample bounds are only compared to tell integer
+    /// from normalized channels. Zero `bytesPlane` fields, as required for supercompressed data, match any
+    /// format.
+    ///
+    /// ```rust
+    /// use ktx2::{BasicDataFormatDescriptor, BasicDataFormatDescriptorBuilder, Format};
+    ///
+    /// let block = BasicDataFormatDescriptorBuilder::from_format(Format::R16G16B16A16_SFLOAT)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// let dfd = BasicDataFormatDescriptor::parse(&block[8..]).unwrap();
+    /// assert_eq!(dfd.format(), Ok(Format::R16G16B16A16_SFLOAT));
+    /// ```
+    pub fn format(&self) -> Result<Format, FormatInferenceError> {
+        match self.header.color_model {
+            Some(ColorModel::RGBSDA)
+            | Some(ColorModel::BC1A)
+            | Some(ColorModel::BC2)
+            | Some(ColorModel::BC3)
+            | Some(ColorModel::BC4)
+            | Some(ColorModel::BC5)
+            | Some(ColorModel::BC6H)
+            | Some(ColorModel::BC7)
+            | Some(ColorModel::ETC2)
+            | Some(ColorModel::ASTC) => {}
+            model => return Err(FormatInferenceError::UnsupportedColorModel(model)),
+        }
+
+        let is_srgb = |transfer_function| transfer_function == Some(TransferFunction::SRGB);
+        let samples = self.sample_information().collect::<Vec<_>>();
+        Format::ALL
+            .iter()
+            .copied()
+            .find(|&format| {
+                let candidate = match canonical(format) {
+                    Some(candidate) => candidate,
+                    None => return false,
+                };
+                candidate.header.color_model == self.header.color_model
+                    && is_srgb(candidate.header.transfer_function) == is_srgb(self.header.transfer_function)
+                    && candidate.header.texel_block_dimensions == self.header.texel_block_dimensions
+                    && (self.header.bytes_planes == [0; 8] || candidate.header.bytes_planes == self.header.bytes_planes)
+                    && candidate.samples.len() == samples.len()
+                    && candidate
+                        .samples
+                        .iter()
+                        .zip(&samples)
+                        .all(|(expected, sample)| samples_match(expected, sample))
+            })
+            .ok_or(FormatInferenceError::NoMatchingFormat)
+    }
+}
+
+fn samples_match(expected: &SampleInformation, sample: &SampleInformation) -> bool {
+    // Some writers omit the linear qualifier on the alpha channel of sRGB formats
+    let qualifiers = |sample: &SampleInformation| sample.channel_type_qualifiers - ChannelTypeQualifiers::LINEAR;
+    let is_float = expected.channel_type_qualifiers.contains(ChannelTypeQualifiers::FLOAT);
+    expected.bit_offset == sample.bit_offset
+        && expected.bit_length == sample.bit_length
+        && expected.channel_type == sample.channel_type
+        && qualifiers(expected) == qualifiers(sample)
+        && (is_float || (expected.upper == 1) == (sample.upper == 1))
+}
+
+// Channel ids of the RGBSDA color model
+const RED: u32 = 0;
+const GREEN: u32 = 1;
+const BLUE: u32 = 2;
+const STENCIL: u32 = 13;
+const DEPTH: u32 = 14;
+const ALPHA: u32 = 15;
+
+#[derive(Copy, Clone)]
+enum CompressionScheme {
+    Bc1Rgb,
+    Bc1Rgba,
+    Bc2,
+    Bc3,
+    Bc4,
+    Bc5,
+    Bc6h,
+    Bc7,
+    Etc2R8G8B8,
+    Etc2R8G8B8A1,
+    Etc2R8G8B8A8,
+    EacR11,
+    EacR11G11,
+    Astc,
+}
+
+impl CompressionScheme {
+    /// Color model, bytes per block and `(channel, bit offset, bit length)` of each sample.
+    fn layout(self) -> (ColorModel, u32, &'static [(u32, u32, u32)]) {
+        // Channel ids are specific to each color model, see the Khronos Data Format Specification.
+        match self {
+            Self::Bc1Rgb => (ColorModel::BC1A, 8, &[(0, 0, 64)]),
+            Self::Bc1Rgba => (ColorModel::BC1A, 8, &[(1, 0, 64)]),
+            Self::Bc2 => (ColorModel::BC2, 16, &[(15, 0, 64), (0, 64, 64)]),
+            Self::Bc3 => (ColorModel::BC3, 16, &[(15, 0, 64), (0, 64, 64)]),
+            Self::Bc4 => (ColorModel::BC4, 8, &[(0, 0, 64)]),
+            Self::Bc5 => (ColorModel::BC5, 16, &[(0, 0, 64), (1, 64, 64)]),
+            Self::Bc6h => (ColorModel::BC6H, 16, &[(0, 0, 128)]),
+            Self::Bc7 => (ColorModel::BC7, 16, &[(0, 0, 128)]),
+            Self::Etc2R8G8B8 => (ColorModel::ETC2, 8, &[(2, 0, 64)]),
+            Self::Etc2R8G8B8A1 => (ColorModel::ETC2, 8, &[(2, 0, 64), (15, 0, 64)]),
+            Self::Etc2R8G8B8A8 => (ColorModel::ETC2, 16, &[(15, 0, 64), (2, 64, 64)]),
+            Self::EacR11 => (ColorModel::ETC2, 8, &[(0, 0, 64)]),
+            Self::EacR11G11 => (ColorModel::ETC2, 16, &[(0, 0, 64), (1, 64, 64)]),
+            Self::Astc => (ColorModel::ASTC, 16, &[(0, 0, 128)]),
+        }
+    }
+}
+
+fn channel_type_qualifiers(channel: u32, suffix: Suffix) -> ChannelTypeQualifiers {
+    match suffix {
+        Suffix::Unorm | Suffix::Uint => ChannelTypeQualifiers::empty(),
+        Suffix::Snorm | Suffix::Sint => ChannelTypeQualifiers::SIGNED,
+        Suffix::Sfloat => ChannelTypeQualifiers::SIGNED | ChannelTypeQualifiers::FLOAT,
+        Suffix::Ufloat => ChannelTypeQualifiers::FLOAT,
+        Suffix::Srgb if channel == ALPHA => ChannelTypeQualifiers::LINEAR,
+        Suffix::Srgb => ChannelTypeQualifiers::empty(),
+    }
+}
+
+fn header(color_model: ColorModel, color: bool, suffix: Suffix) -> BasicDataFormatDescriptorBuilder {
+    let mut builder = BasicDataFormatDescriptorBuilder::new();
+    builder
+        .color_model(Some(color_model))
+        .color_primaries(if color { Some(ColorPrimaries::BT709) } else { None })
+        .transfer_function(Some(if suffix == Suffix::Srgb {
+            TransferFunction::SRGB
+        } else {
+            TransferFunction::Linear
+        }));
+    builder
+}
+
+fn uncompressed_sample(channel: u32, bit_length: u32, bit_offset: u32, suffix: Suffix) -> SampleInformation {
+    let (lower, upper) = match suffix {
+        Suffix::Unorm | Suffix::Srgb if bit_length >= 32 => (0, u32::MAX),
+        Suffix::Unorm | Suffix::Srgb => (0, (1 << bit_length) - 1),
+        Suffix::Snorm => {
+            let upper: u32 = if bit_length > 32 {
+                0x7FFF_FFFF
+            } else {
+                (1 << (bit_length - 1)) - 1
+            };
+            ((!upper).wrapping_add(1), upper)
+        }
+        Suffix::Uint => (0, 1),
+        Suffix::Sint => (!0, 1),
+        Suffix::Sfloat => ((-1.0f32).to_bits(), 1.0f32.to_bits()),
+        Suffix::Ufloat => (0.0f32.to_bits(), 1.0f32.to_bits()),
+    };
+    SampleInformation {
+        bit_offset,
+        bit_length,
+        channel_type: channel,
+        channel_type_qualifiers: channel_type_qualifiers(channel, suffix),
+        sample_positions: [0; 4],
+        lower,
+        upper,
+    }
+}
+
+/// Formats with one byte-aligned channel per `bytes` bytes, in R, G, B, A order (B, G, R, A if `bgr`).
+fn unpacked(channels: u32, bytes: u32, bgr: bool, suffix: Suffix) -> BasicDataFormatDescriptorBuilder {
+    let mut builder = header(ColorModel::RGBSDA, true, suffix);
+    builder.bytes_planes([channels * bytes, 0, 0, 0, 0, 0, 0, 0]);
+    let order = if bgr {
+        [BLUE, GREEN, RED, ALPHA]
+    } else {
+        [RED, GREEN, BLUE, ALPHA]
+    };
+    for (i, &channel) in order.iter().take(channels as usize).enumerate() {
+        builder.sample(uncompressed_sample(channel, bytes * 8, i as u32 * bytes * 8, suffix));
+    }
+    builder
+}
+
+/// Formats with channels packed into a single word, listed from the least significant bit upwards.
+fn packed(bits: &[u32], channels: &[u32], suffix: Suffix) -> BasicDataFormatDescriptorBuilder {
+    let mut builder = header(ColorModel::RGBSDA, true, suffix);
+    builder.bytes_planes([bits.iter().sum::<u32>() / 8, 0, 0, 0, 0, 0, 0, 0]);
+    let mut bit_offset = 0;
+    for (&bit_length, &channel) in bits.iter().zip(channels) {
+        builder.sample(uncompressed_sample(channel, bit_length, bit_offset, suffix));
+        bit_offset += bit_length;
+    }
+    builder
+}
+
+fn depth_stencil(depth_bits: u32, stencil_bits: u32, bytes: u32) -> BasicDataFormatDescriptorBuilder {
+    let mut builder = header(ColorModel::RGBSDA, false, Suffix::Unorm);
+    builder.bytes_planes([bytes, 0, 0, 0, 0, 0, 0, 0]);
+    let depth_suffix = if depth_bits == 32 {
+        Suffix::Sfloat
+    } else {
+        Suffix::Unorm
+    };
+    if depth_bits == 24 && stencil_bits == 8 {
+        // D24_UNORM_S8_UINT keeps stencil in the least significant bits
+        builder
+            .sample(uncompressed_sample(STENCIL, 8, 0, Suffix::Uint))
+            .sample(uncompressed_sample(DEPTH, 24, 8, Suffix::Unorm));
+        return builder;
+    }
+    if depth_bits > 0 {
+        builder.sample(uncompressed_sample(DEPTH, depth_bits, 0, depth_suffix));
+    }
+    if stencil_bits > 0 {
+        builder.sample(uncompressed_sample(STENCIL, stencil_bits, depth_bits, Suffix::Uint));
+    }
+    builder
+}
+
+fn shared_exponent() -> BasicDataFormatDescriptorBuilder {
+    let mut builder = header(ColorModel::RGBSDA, true, Suffix::Ufloat);
+    builder.bytes_planes([4, 0, 0, 0, 0, 0, 0, 0]);
+    for (i, &channel) in [RED, GREEN, BLUE].iter().enumerate() {
+        builder
+            .sample(SampleInformation {
+                bit_offset: i as u32 * 9,
+                bit_length: 9,
+                channel_type: channel,
+                channel_type_qualifiers: ChannelTypeQualifiers::empty(),
+                sample_positions: [0; 4],
+                lower: 0,
+                upper: 8448,
+            })
+            .sample(SampleInformation {
+                bit_offset: 27,
+                bit_length: 5,
+                channel_type: channel,
+                channel_type_qualifiers: ChannelTypeQualifiers::EXPONENT,
+                sample_positions: [0; 4],
+                lower: 15,
+                upper: 31,
+            });
+    }
+    builder
+}
+
+fn compressed(scheme: CompressionScheme, width: u32, height: u32, suffix: Suffix) -> BasicDataFormatDescriptorBuilder {
+    let (color_model, bytes, samples) = scheme.layout();
+    let mut builder = header(color_model, true, suffix);
+    builder
+        .texel_block_dimensions([width, height, 1, 1])
+        .bytes_planes([bytes, 0, 0, 0, 0, 0, 0, 0]);
+    let (lower, upper) = match suffix {
+        Suffix::Unorm | Suffix::Srgb => (0, u32::MAX),
+        Suffix::Snorm => (0x8000_0000, 0x7FFF_FFFF),
+        Suffix::Uint => (0, 1),
+        Suffix::Sint => (!0, 1),
+        Suffix::Sfloat => ((-1.0f32).to_bits(), 1.0f32.to_bits()),
+        Suffix::Ufloat => (0.0f32.to_bits(), 1.0f32.to_bits()),
+    };
+    for &(channel, bit_offset, bit_length) in samples {
+        builder.sample(SampleInformation {
+            bit_offset,
+            bit_length,
+            channel_type: channel,
+            channel_type_qualifiers: channel_type_qualifiers(channel, suffix),
+            sample_positions: [0; 4],
+            lower,
+            upper,
+        });
+    }
+    builder
+}
+
+fn canonical(format: Format) -> Option<BasicDataFormatDescriptorBuilder> {
+    use CompressionScheme::*;
+    use Suffix::*;
+
+    Some(match format {
+        Format::R4G4_UNORM_PACK8 => packed(&[4, 4], &[GREEN, RED], Unorm),
+        Format::R4G4B4A4_UNORM_PACK16 => packed(&[4, 4, 4, 4], &[ALPHA, BLUE, GREEN, RED], Unorm),
+        Format::B4G4R4A4_UNORM_PACK16 => packed(&[4, 4, 4, 4], &[ALPHA, RED, GREEN, BLUE], Unorm),
+        Format::R5G6B5_UNORM_PACK16 => packed(&[5, 6, 5], &[BLUE, GREEN, RED], Unorm),
+        Format::B5G6R5_UNORM_PACK16 => packed(&[5, 6, 5], &[RED, GREEN, BLUE], Unorm),
+        Format::R5G5B5A1_UNORM_PACK16 => packed(&[1, 5, 5, 5], &[ALPHA, BLUE, GREEN, RED], Unorm),
+        Format::B5G5R5A1_UNORM_PACK16 => packed(&[1, 5, 5, 5], &[ALPHA, RED, GREEN, BLUE], Unorm),
+        Format::A1R5G5B5_UNORM_PACK16 => packed(&[5, 5, 5, 1], &[BLUE, GREEN, RED, ALPHA], Unorm),
+        Format::R8_UNORM => unpacked(1, 1, false, Unorm),
+        Format::R8_SNORM => unpacked(1, 1, false, Snorm),
+        Format::R8_UINT => unpacked(1, 1, false, Uint),
+        Format::R8_SINT => unpacked(1, 1, false, Sint),
+        Format::R8_SRGB => unpacked(1, 1, false, Srgb),
+        Format::R8G8_UNORM => unpacked(2, 1, false, Unorm),
+        Format::R8G8_SNORM => unpacked(2, 1, false, Snorm),
+        Format::R8G8_UINT => unpacked(2, 1, false, Uint),
+        Format::R8G8_SINT => unpacked(2, 1, false, Sint),
+        Format::R8G8_SRGB => unpacked(2, 1, false, Srgb),
+        Format::R8G8B8_UNORM => unpacked(3, 1, false, Unorm),
+        Format::R8G8B8_SNORM => unpacked(3, 1, false, Snorm),
+        Format::R8G8B8_UINT => unpacked(3, 1, false, Uint),
+        Format::R8G8B8_SINT => unpacked(3, 1, false, Sint),
+        Format::R8G8B8_SRGB => unpacked(3, 1, false, Srgb),
+        Format::B8G8R8_UNORM => unpacked(3, 1, true, Unorm),
+        Format::B8G8R8_SNORM => unpacked(3, 1, true, Snorm),
+        Format::B8G8R8_UINT => unpacked(3, 1, true, Uint),
+        Format::B8G8R8_SINT => unpacked(3, 1, true, Sint),
+        Format::B8G8R8_SRGB => unpacked(3, 1, true, Srgb),
+        Format::R8G8B8A8_UNORM => unpacked(4, 1, false, Unorm),
+        Format::R8G8B8A8_SNORM => unpacked(4, 1, false, Snorm),
+        Format::R8G8B8A8_UINT => unpacked(4, 1, false, Uint),
+        Format::R8G8B8A8_SINT => unpacked(4, 1, false, Sint),
+        Format::R8G8B8A8_SRGB => unpacked(4, 1, false, Srgb),
+        Format::B8G8R8A8_UNORM => unpacked(4, 1, true, Unorm),
+        Format::B8G8R8A8_SNORM => unpacked(4, 1, true, Snorm),
+        Format::B8G8R8A8_UINT => unpacked(4, 1, true, Uint),
+        Format::B8G8R8A8_SINT => unpacked(4, 1, true, Sint),
+        Format::B8G8R8A8_SRGB => unpacked(4, 1, true, Srgb),
+        Format::A2R10G10B10_UNORM_PACK32 => packed(&[10, 10, 10, 2], &[BLUE, GREEN, RED, ALPHA], Unorm),
+        Format::A2R10G10B10_SNORM_PACK32 => packed(&[10, 10, 10, 2], &[BLUE, GREEN, RED, ALPHA], Snorm),
+        Format::A2R10G10B10_UINT_PACK32 => packed(&[10, 10, 10, 2], &[BLUE, GREEN, RED, ALPHA], Uint),
+        Format::A2R10G10B10_SINT_PACK32 => packed(&[10, 10, 10, 2], &[BLUE, GREEN, RED, ALPHA], Sint),
+        Format::A2B10G10R10_UNORM_PACK32 => packed(&[10, 10, 10, 2], &[RED, GREEN, BLUE, ALPHA], Unorm),
+        Format::A2B10G10R10_SNORM_PACK32 => packed(&[10, 10, 10, 2], &[RED, GREEN, BLUE, ALPHA], Snorm),
+        Format::A2B10G10R10_UINT_PACK32 => packed(&[10, 10, 10, 2], &[RED, GREEN, BLUE, ALPHA], Uint),
+        Format::A2B10G10R10_SINT_PACK32 => packed(&[10, 10, 10, 2], &[RED, GREEN, BLUE, ALPHA], Sint),
+        Format::R16_UNORM => unpacked(1, 2, false, Unorm),
+        Format::R16_SNORM => unpacked(1, 2, false, Snorm),
+        Format::R16_UINT => unpacked(1, 2, false, Uint),
+        Format::R16_SINT => unpacked(1, 2, false, Sint),
+        Format::R16_SFLOAT => unpacked(1, 2, false, Sfloat),
+        Format::R16G16_UNORM => unpacked(2, 2, false, Unorm),
+        Format::R16G16_SNORM => unpacked(2, 2, false, Snorm),
+        Format::R16G16_UINT => unpacked(2, 2, false, Uint),
+        Format::R16G16_SINT => unpacked(2, 2, false, Sint),
+        Format::R16G16_SFLOAT => unpacked(2, 2, false, Sfloat),
+        Format::R16G16B16_UNORM => unpacked(3, 2, false, Unorm),
+        Format::R16G16B16_SNORM => unpacked(3, 2, false, Snorm),
+        Format::R16G16B16_UINT => unpacked(3, 2, false, Uint),
+        Format::R16G16B16_SINT => unpacked(3, 2, false, Sint),
+        Format::R16G16B16_SFLOAT => unpacked(3, 2, false, Sfloat),
+        Format::R16G16B16A16_UNORM => unpacked(4, 2, false, Unorm),
+        Format::R16G16B16A16_SNORM => unpacked(4, 2, false, Snorm),
+        Format::R16G16B16A16_UINT => unpacked(4, 2, false, Uint),
+        Format::R16G16B16A16_SINT => unpacked(4, 2, false, Sint),
+        Format::R16G16B16A16_SFLOAT => unpacked(4, 2, false, Sfloat),
+        Format::R32_UINT => unpacked(1, 4, false, Uint),
+        Format::R32_SINT => unpacked(1, 4, false, Sint),
+        Format::R32_SFLOAT => unpacked(1, 4, false, Sfloat),
+        Format::R32G32_UINT => unpacked(2, 4, false, Uint),
+        Format::R32G32_SINT => unpacked(2, 4, false, Sint),
+        Format::R32G32_SFLOAT => unpacked(2, 4, false, Sfloat),
+        Format::R32G32B32_UINT => unpacked(3, 4, false, Uint),
+        Format::R32G32B32_SINT => unpacked(3, 4, false, Sint),
+        Format::R32G32B32_SFLOAT => unpacked(3, 4, false, Sfloat),
+        Format::R32G32B32A32_UINT => unpacked(4, 4, false, Uint),
+        Format::R32G32B32A32_SINT => unpacked(4, 4, false, Sint),
+        Format::R32G32B32A32_SFLOAT => unpacked(4, 4, false, Sfloat),
+        Format::R64_UINT => unpacked(1, 8, false, Uint),
+        Format::R64_SINT => unpacked(1, 8, false, Sint),
+        Format::R64_SFLOAT => unpacked(1, 8, false, Sfloat),
+        Format::R64G64_UINT => unpacked(2, 8, false, Uint),
+        Format::R64G64_SINT => unpacked(2, 8, false, Sint),
+        Format::R64G64_SFLOAT => unpacked(2, 8, false, Sfloat),
+        Format::R64G64B64_UINT => unpacked(3, 8, false, Uint),
+        Format::R64G64B64_SINT => unpacked(3, 8, false, Sint),
+        Format::R64G64B64_SFLOAT => unpacked(3, 8, false, Sfloat),
+        Format::R64G64B64A64_UINT => unpacked(4, 8, false, Uint),
+        Format::R64G64B64A64_SINT => unpacked(4, 8, false, Sint),
+        Format::R64G64B64A64_SFLOAT => unpacked(4, 8, false, Sfloat),
+        Format::B10G11R11_UFLOAT_PACK32 => packed(&[11, 11, 10], &[RED, GREEN, BLUE], Ufloat),
+        Format::E5B9G9R9_UFLOAT_PACK32 => shared_exponent(),
+        Format::D16_UNORM => depth_stencil(16, 0, 2),
+        Format::X8_D24_UNORM_PACK32 => depth_stencil(24, 0, 4),
+        Format::D32_SFLOAT => depth_stencil(32, 0, 4),
+        Format::S8_UINT => depth_stencil(0, 8, 1),
+        Format::D16_UNORM_S8_UINT => depth_stencil(16, 8, 4),
+        Format::D24_UNORM_S8_UINT => depth_stencil(24, 8, 4),
+        Format::D32_SFLOAT_S8_UINT => depth_stencil(32, 8, 8),
+        Format::BC1_RGB_UNORM_BLOCK => compressed(Bc1Rgb, 4, 4, Unorm),
+        Format::BC1_RGB_SRGB_BLOCK => compressed(Bc1Rgb, 4, 4, Srgb),
+        Format::BC1_RGBA_UNORM_BLOCK => compressed(Bc1Rgba, 4, 4, Unorm),
+        Format::BC1_RGBA_SRGB_BLOCK => compressed(Bc1Rgba, 4, 4, Srgb),
+        Format::BC2_UNORM_BLOCK => compressed(Bc2, 4, 4, Unorm),
+        Format::BC2_SRGB_BLOCK => compressed(Bc2, 4, 4, Srgb),
+        Format::BC3_UNORM_BLOCK => compressed(Bc3, 4, 4, Unorm),
+        Format::BC3_SRGB_BLOCK => compressed(Bc3, 4, 4, Srgb),
+        Format::BC4_UNORM_BLOCK => compressed(Bc4, 4, 4, Unorm),
+        Format::BC4_SNORM_BLOCK => compressed(Bc4, 4, 4, Snorm),
+        Format::BC5_UNORM_BLOCK => compressed(Bc5, 4, 4, Unorm),
+        Format::BC5_SNORM_BLOCK => compressed(Bc5, 4, 4, Snorm),
+        Format::BC6H_UFLOAT_BLOCK => compressed(Bc6h, 4, 4, Ufloat),
+        Format::BC6H_SFLOAT_BLOCK => compressed(Bc6h, 4, 4, Sfloat),
+        Format::BC7_UNORM_BLOCK => compressed(Bc7, 4, 4, Unorm),
+        Format::BC7_SRGB_BLOCK => compressed(Bc7, 4, 4, Srgb),
+        Format::ETC2_R8G8B8_UNORM_BLOCK => compressed(Etc2R8G8B8, 4, 4, Unorm),
+        Format::ETC2_R8G8B8_SRGB_BLOCK => compressed(Etc2R8G8B8, 4, 4, Srgb),
+        Format::ETC2_R8G8B8A1_UNORM_BLOCK => compressed(Etc2R8G8B8A1, 4, 4, Unorm),
+        Format::ETC2_R8G8B8A1_SRGB_BLOCK => compressed(Etc2R8G8B8A1, 4, 4, Srgb),
+        Format::ETC2_R8G8B8A8_UNORM_BLOCK => compressed(Etc2R8G8B8A8, 4, 4, Unorm),
+        Format::ETC2_R8G8B8A8_SRGB_BLOCK => compressed(Etc2R8G8B8A8, 4, 4, Srgb),
+        Format::EAC_R11_UNORM_BLOCK => compressed(EacR11, 4, 4, Unorm),
+        Format::EAC_R11_SNORM_BLOCK => compressed(EacR11, 4, 4, Snorm),
+        Format::EAC_R11G11_UNORM_BLOCK => compressed(EacR11G11, 4, 4, Unorm),
+        Format::EAC_R11G11_SNORM_BLOCK => compressed(EacR11G11, 4, 4, Snorm),
+        Format::ASTC_4x4_UNORM_BLOCK => compressed(Astc, 4, 4, Unorm),
+        Format::ASTC_4x4_SRGB_BLOCK => compressed(Astc, 4, 4, Srgb),
+        Format::ASTC_4x4_SFLOAT_BLOCK => compressed(Astc, 4, 4, Sfloat),
+        Format::ASTC_5x4_UNORM_BLOCK => compressed(Astc, 5, 4, Unorm),
+        Format::ASTC_5x4_SRGB_BLOCK => compressed(Astc, 5, 4, Srgb),
+        Format::ASTC_5x4_SFLOAT_BLOCK => compressed(Astc, 5, 4, Sfloat),
+        Format::ASTC_5x5_UNORM_BLOCK => compressed(Astc, 5, 5, Unorm),
+        Format::ASTC_5x5_SRGB_BLOCK => compressed(Astc, 5, 5, Srgb),
+        Format::ASTC_5x5_SFLOAT_BLOCK => compressed(Astc, 5, 5, Sfloat),
+        Format::ASTC_6x5_UNORM_BLOCK => compressed(Astc, 6, 5, Unorm),
+        Format::ASTC_6x5_SRGB_BLOCK => compressed(Astc, 6, 5, Srgb),
+        Format::ASTC_6x5_SFLOAT_BLOCK => compressed(Astc, 6, 5, Sfloat),
+        Format::ASTC_6x6_UNORM_BLOCK => compressed(Astc, 6, 6, Unorm),
+        Format::ASTC_6x6_SRGB_BLOCK => compressed(Astc, 6, 6, Srgb),
+        Format::ASTC_6x6_SFLOAT_BLOCK => compressed(Astc, 6, 6, Sfloat),
+        Format::ASTC_8x5_UNORM_BLOCK => compressed(Astc, 8, 5, Unorm),
+        Format::ASTC_8x5_SRGB_BLOCK => compressed(Astc, 8, 5, Srgb),
+        Format::ASTC_8x5_SFLOAT_BLOCK => compressed(Astc, 8, 5, Sfloat),
+        Format::ASTC_8x6_UNORM_BLOCK => compressed(Astc, 8, 6, Unorm),
+        Format::ASTC_8x6_SRGB_BLOCK => compressed(Astc, 8, 6, Srgb),
+        Format::ASTC_8x6_SFLOAT_BLOCK => compressed(Astc, 8, 6, Sfloat),
+        Format::ASTC_8x8_UNORM_BLOCK => compressed(Astc, 8, 8, Unorm),
+        Format::ASTC_8x8_SRGB_BLOCK => compressed(Astc, 8, 8, Srgb),
+        Format::ASTC_8x8_SFLOAT_BLOCK => compressed(Astc, 8, 8, Sfloat),
+        Format::ASTC_10x5_UNORM_BLOCK => compressed(Astc, 10, 5, Unorm),
+        Format::ASTC_10x5_SRGB_BLOCK => compressed(Astc, 10, 5, Srgb),
+        Format::ASTC_10x5_SFLOAT_BLOCK => compressed(Astc, 10, 5, Sfloat),
+        Format::ASTC_10x6_UNORM_BLOCK => compressed(Astc, 10, 6, Unorm),
+        Format::ASTC_10x6_SRGB_BLOCK => compressed(Astc, 10, 6, Srgb),
+        Format::ASTC_10x6_SFLOAT_BLOCK => compressed(Astc, 10, 6, Sfloat),
+        Format::ASTC_10x8_UNORM_BLOCK => compressed(Astc, 10, 8, Unorm),
+        Format::ASTC_10x8_SRGB_BLOCK => compressed(Astc, 10, 8, Srgb),
+        Format::ASTC_10x8_SFLOAT_BLOCK => compressed(Astc, 10, 8, Sfloat),
+        Format::ASTC_10x10_UNORM_BLOCK => compressed(Astc, 10, 10, Unorm),
+        Format::ASTC_10x10_SRGB_BLOCK => compressed(Astc, 10, 10, Srgb),
+        Format::ASTC_10x10_SFLOAT_BLOCK => compressed(Astc, 10, 10, Sfloat),
+        Format::ASTC_12x10_UNORM_BLOCK => compressed(Astc, 12, 10, Unorm),
+        Format::ASTC_12x10_SRGB_BLOCK => compressed(Astc, 12, 10, Srgb),
+        Format::ASTC_12x10_SFLOAT_BLOCK => compressed(Astc, 12, 10, Sfloat),
+        Format::ASTC_12x12_UNORM_BLOCK => compressed(Astc, 12, 12, Unorm),
+        Format::ASTC_12x12_SRGB_BLOCK => compressed(Astc, 12, 12, Srgb),
+        Format::ASTC_12x12_SFLOAT_BLOCK => compressed(Astc, 12, 12, Sfloat),
+        _ => return None,
+    })
+}
+
+#[test]
+fn test_basic_data_format_descriptor_round_trip() {
+    use crate::test_util::{test_tex_dfd, TEST_TEX};
+
+    let reader = crate::Reader::new(TEST_TEX).unwrap();
+
+    let block = reader.data_format_descriptors().next().unwrap();
+    assert_eq!(block.header, DataFormatDescriptorHeader::BASIC);
+    let basic = BasicDataFormatDescriptor::parse(block.data).unwrap();
+
+    let bytes = BasicDataFormatDescriptorBuilder::from(&basic).build().unwrap();
+    assert_eq!(bytes, test_tex_dfd());
+}
+
+#[test]
+fn test_canonical_data_format_descriptor() {
+    let dfd = crate::test_util::test_tex_dfd();
+
+    let bytes = BasicDataFormatDescriptorBuilder::from_format(Format::R8G8B8A8_UINT)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(bytes, dfd);
+
+    let bytes = BasicDataFormatDescriptorBuilder::from_format(Format::R5G6B5_UNORM_PACK16)
+        .unwrap()
+        .build()
+        .unwrap();
+    let dfd = BasicDataFormatDescriptor::parse(&bytes[DataFormatDescriptorHeader::LENGTH..]).unwrap();
+    let samples = dfd
+        .sample_information()
+        .map(|sample| (sample.channel_type, sample.bit_offset, sample.bit_length, sample.upper))
+        .collect::<Vec<_>>();
+    assert_eq!(samples, [(BLUE, 0, 5, 31), (GREEN, 5, 6, 63), (RED, 11, 5, 31)]);
+}
+
+#[test]
+fn test_format_from_canonical_data_format_descriptor() {
+    for &format in Format::ALL {
+        let bytes = BasicDataFormatDescriptorBuilder::from_format(format)
+            .unwrap()
+            .build()
+            .unwrap();
+        let dfd = BasicDataFormatDescriptor::parse(&bytes[DataFormatDescriptorHeader::LENGTH..]).unwrap();
+        assert_eq!(dfd.format(), Ok(format));
+    }
+
+    let bytes = BasicDataFormatDescriptorBuilder::new()
+        .color_model(Some(ColorModel::UASTC))
+        .build()
+        .unwrap();
+    let dfd = BasicDataFormatDescriptor::parse(&bytes[DataFormatDescriptorHeader::LENGTH..]).unwrap();
+    assert_eq!(
+        dfd.format(),
+        Err(FormatInferenceError::UnsupportedColorModel(Some(ColorModel::UASTC)))
+    );
+}
@@ -0,0 +1,203 @@
+use crate::{DxgiFormat, Format, Reader};
+use core::convert::TryInto;
+
+// Packed 16-bit formats are named from the most significant bit in Vulkan and from the least significant bit
+// in DXGI, so `R5G6B5_UNORM_PACK16` is `B5G6R5_UNORM`. Typeless and video formats have no equivalent.
+impl Format {
+    /// Equivalent `DXGI_FORMAT`, if any.
+    ///
+    /// ```rust
+    /// # use ktx2::{DxgiFormat, Format};
+    /// assert_eq!(Format::BC7_SRGB_BLOCK.to_dxgi(), Some(DxgiFormat::BC7_UNORM_SRGB));
+    /// assert_eq!(Format::ETC2_R8G8B8_UNORM_BLOCK.to_dxgi(), None);
+    /// ```
+    pub const fn to_dxgi(self) -> Option<DxgiFormat> {
+        match self {
+            Format::R32G32B32A32_SFLOAT => Some(DxgiFormat::R32G32B32A32_FLOAT),
+            Format::R32G32B32A32_UINT => Some(DxgiFormat::R32G32B32A32_UINT),
+            Format::R32G32B32A32_SINT => Some(DxgiFormat::R32G32B32A32_SINT),
+            Format::R32G32B32_SFLOAT => Some(DxgiFormat::R32G32B32_FLOAT),
+            Format::R32G32B32_UINT => Some(DxgiFormat::R32G32B32_UINT),
+            Format::R32G32B32_SINT => Some(DxgiFormat::R32G32B32_SINT),
+            Format::R16G16B16A16_SFLOAT => Some(DxgiFormat::R16G16B16A16_FLOAT),
+            Format::R16G16B16A16_UNORM => Some(DxgiFormat::R16G16B16A16_UNORM),
+            Format::R16G16B16A16_UINT => Some(DxgiFormat::R16G16B16A16_UINT),
+            Format::R16G16B16A16_SNORM => Some(DxgiFormat::R16G16B16A16_SNORM),
+            Format::R16G16B16A16_SINT => Some(DxgiFormat::R16G16B16A16_SINT),
+            Format::R32G32_SFLOAT => Some(DxgiFormat::R32G32_FLOAT),
+            Format::R32G32_UINT => Some(DxgiFormat::R32G32_UINT),
+            Format::R32G32_SINT => Some(DxgiFormat::R32G32_SINT),
+            Format::D32_SFLOAT_S8_UINT => Some(DxgiFormat::D32_FLOAT_S8X24_UINT),
+            Format::A2B10G10R10_UNORM_PACK32 => Some(DxgiFormat::R10G10B10A2_UNORM),
+            Format::A2B10G10R10_UINT_PACK32 => Some(DxgiFormat::R10G10B10A2_UINT),
+            Format::B10G11R11_UFLOAT_PACK32 => Some(DxgiFormat::R11G11B10_FLOAT),
+            Format::R8G8B8A8_UNORM => Some(DxgiFormat::R8G8B8A8_UNORM),
+            Format::R8G8B8A8_SRGB => Some(DxgiFormat::R8G8B8A8_UNORM_SRGB),
+            Format::R8G8B8A8_UINT => Some(DxgiFormat::R8G8B8A8_UINT),
+            Format::R8G8B8A8_SNORM => Some(DxgiFormat::R8G8B8A8_SNORM),
+            Format::R8G8B8A8_SINT => Some(DxgiFormat::R8G8B8A8_SINT),
+            Format::R16G16_SFLOAT => Some(DxgiFormat::R16G16_FLOAT),
+            Format::R16G16_UNORM => Some(DxgiFormat::R16G16_UNORM),
+            Format::R16G16_UINT => Some(DxgiFormat::R16G16_UINT),
+            Format::R16G16_SNORM => Some(DxgiFormat::R16G16_SNORM),
+            Format::R16G16_SINT => Some(DxgiFormat::R16G16_SINT),
+            Format::D32_SFLOAT => Some(DxgiFormat::D32_FLOAT),
+            Format::R32_SFLOAT => Some(DxgiFormat::R32_FLOAT),
+            Format::R32_UINT => Some(DxgiFormat::R32_UINT),
+            Format::R32_SINT => Some(DxgiFormat::R32_SINT),
+            Format::D24_UNORM_S8_UINT => Some(DxgiFormat::D24_UNORM_S8_UINT),
+            Format::R8G8_UNORM => Some(DxgiFormat::R8G8_UNORM),
+            Format::R8G8_UINT => Some(DxgiFormat::R8G8_UINT),
+            Format::R8G8_SNORM => Some(DxgiFormat::R8G8_SNORM),
+            Format::R8G8_SINT => Some(DxgiFormat::R8G8_SINT),
+            Format::R16_SFLOAT => Some(DxgiFormat::R16_FLOAT),
+            Format::D16_UNORM => Some(DxgiFormat::D16_UNORM),
+            Format::R16_UNORM => Some(DxgiFormat::R16_UNORM),
+            Format::R16_UINT => Some(DxgiFormat::R16_UINT),
+            Format::R16_SNORM => Some(DxgiFormat::R16_SNORM),
+            Format::R16_SINT => Some(DxgiFormat::R16_SINT),
+            Format::R8_UNORM => Some(DxgiFormat::R8_UNORM),
+            Format::R8_UINT => Some(DxgiFormat::R8_UINT),
+            Format::R8_SNORM => Some(DxgiFormat::R8_SNORM),
+            Format::R8_SINT => Some(DxgiFormat::R8_SINT),
+            Format::E5B9G9R9_UFLOAT_PACK32 => Some(DxgiFormat::R9G9B9E5_SHAREDEXP),
+            Format::BC1_RGBA_UNORM_BLOCK => Some(DxgiFormat::BC1_UNORM),
+            Format::BC1_RGBA_SRGB_BLOCK => Some(DxgiFormat::BC1_UNORM_SRGB),
+            Format::BC2_UNORM_BLOCK => Some(DxgiFormat::BC2_UNORM),
+            Format::BC2_SRGB_BLOCK => Some(DxgiFormat::BC2_UNORM_SRGB),
+            Format::BC3_UNORM_BLOCK => Some(DxgiFormat::BC3_UNORM),
+            Format::BC3_SRGB_BLOCK => Some(DxgiFormat::BC3_UNORM_SRGB),
+            Format::BC4_UNORM_BLOCK => Some(DxgiFormat::BC4_UNORM),
+            Format::BC4_SNORM_BLOCK => Some(DxgiFormat::BC4_SNORM),
+            Format::BC5_UNORM_BLOCK => Some(DxgiFormat::BC5_UNORM),
+            Format::BC5_SNORM_BLOCK => Some(DxgiFormat::BC5_SNORM),
+            Format::R5G6B5_UNORM_PACK16 => Some(DxgiFormat::B5G6R5_UNORM),
+            Format::A1R5G5B5_UNORM_PACK16 => Some(DxgiFormat::B5G5R5A1_UNORM),
+            Format::B8G8R8A8_UNORM => Some(DxgiFormat::B8G8R8A8_UNORM),
+            Format::B8G8R8A8_SRGB => Some(DxgiFormat::B8G8R8A8_UNORM_SRGB),
+            Format::BC6H_UFLOAT_BLOCK => Some(DxgiFormat::BC6H_UF16),
+            Format::BC6H_SFLOAT_BLOCK => Some(DxgiFormat::BC6H_SF16),
+            Format::BC7_UNORM_BLOCK => Some(DxgiFormat::BC7_UNORM),
+            Format::BC7_SRGB_BLOCK => Some(DxgiFormat::BC7_UNORM_SRGB),
+            _ => None,
+        }
+    }
+}
+
+impl DxgiFormat {
+    /// Equivalent format, if any.
+    pub const fn to_format(self) -> Option<Format> {
+        match self {
+            DxgiFormat::R32G32B32A32_FLOAT => Some(Format::R32G32B32A32_SFLOAT),
+            DxgiFormat::R32G32B32A32_UINT => Some(Format::R32G32B32A32_UINT),
+            DxgiFormat::R32G32B32A32_SINT => Some(Format::R32G32B32A32_SINT),
+            DxgiFormat::R32G32B32_FLOAT => Some(Format::R32G32B32_SFLOAT),
+            DxgiFormat::R32G32B32_UINT => Some(Format::R32G32B32_UINT),
+            DxgiFormat::R32G32B32_SINT => Some(Format::R32G32B32_SINT),
+            DxgiFormat::R16G16B16A16_FLOAT => Some(Format::R16G16B16A16_SFLOAT),
+            DxgiFormat::R16G16B16A16_UNORM => Some(Format::R16G16B16A16_UNORM),
+            DxgiFormat::R16G16B16A16_UINT => Some(Format::R16G16B16A16_UINT),
+            DxgiFormat::R16G16B16A16_SNORM => Some(Format::R16G16B16A16_SNORM),
+            DxgiFormat::R16G16B16A16_SINT => Some(Format::R16G16B16A16_SINT),
+            DxgiFormat::R32G32_FLOAT => Some(Format::R32G32_SFLOAT),
+            DxgiFormat::R32G32_UINT => Some(Format::R32G32_UINT),
+            DxgiFormat::R32G32_SINT => Some(Format::R32G32_SINT),
+            DxgiFormat::D32_FLOAT_S8X24_UINT => Some(Format::D32_SFLOAT_S8_UINT),
+            DxgiFormat::R10G10B10A2_UNORM => Some(Format::A2B10G10R10_UNORM_PACK32),
+            DxgiFormat::R10G10B10A2_UINT => Some(Format::A2B10G10R10_UINT_PACK32),
+            DxgiFormat::R11G11B10_FLOAT => Some(Format::B10G11R11_UFLOAT_PACK32),
+            DxgiFormat::R8G8B8A8_UNORM => Some(Format::R8G8B8A8_UNORM),
+            DxgiFormat::R8G8B8A8_UNORM_SRGB => Some(Format::R8G8B8A8_SRGB),
+            DxgiFormat::R8G8B8A8_UINT => Some(Format::R8G8B8A8_UINT),
+            DxgiFormat::R8G8B8A8_SNORM => Some(Format::R8G8B8A8_SNORM),
+            DxgiFormat::R8G8B8A8_SINT => Some(Format::R8G8B8A8_SINT),
+            DxgiFormat::R16G16_FLOAT => Some(Format::R16G16_SFLOAT),
+            DxgiFormat::R16G16_UNORM => Some(Format::R16G16_UNORM),
+            DxgiFormat::R16G16_UINT => Some(Format::R16G16_UINT),
+            DxgiFormat::R16G16_SNORM => Some(Format::R16G16_SNORM),
+            DxgiFormat::R16G16_SINT => Some(Format::R16G16_SINT),
+            DxgiFormat::D32_FLOAT => Some(Format::D32_SFLOAT),
+            DxgiFormat::R32_FLOAT => Some(Format::R32_SFLOAT),
+            DxgiFormat::R32_UINT => Some(Format::R32_UINT),
+            DxgiFormat::R32_SINT => Some(Format::R32_SINT),
+            DxgiFormat::D24_UNORM_S8_UINT => Some(Format::D24_UNORM_S8_UINT),
+            DxgiFormat::R8G8_UNORM => Some(Format::R8G8_UNORM),
+            DxgiFormat::R8G8_UINT => Some(Format::R8G8_UINT),
+            DxgiFormat::R8G8_SNORM => Some(Format::R8G8_SNORM),
+            DxgiFormat::R8G8_SINT => Some(Format::R8G8_SINT),
+            DxgiFormat::R16_FLOAT => Some(Format::R16_SFLOAT),
+            DxgiFormat::D16_UNORM => Some(Format::D16_UNORM),
+            DxgiFormat::R16_UNORM => Some(Format::R16_UNORM),
+            DxgiFormat::R16_UINT => Some(Format::R16_UINT),
+            DxgiFormat::R16_SNORM => Some(Format::R16_SNORM),
+            DxgiFormat::R16_SINT => Some(Format::R16_SINT),
+            DxgiFormat::R8_UNORM => Some(Format::R8_UNORM),
+            DxgiFormat::R8_UINT => Some(Format::R8_UINT),
+            DxgiFormat::R8_SNORM => Some(Format::R8_SNORM),
+            DxgiFormat::R8_SINT => Some(Format::R8_SINT),
+            DxgiFormat::R9G9B9E5_SHAREDEXP => Some(Format::E5B9G9R9_UFLOAT_PACK32),
+            DxgiFormat::BC1_UNORM => Some(Format::BC1_RGBA_UNORM_BLOCK),
+            DxgiFormat::BC1_UNORM_SRGB => Some(Format::BC1_RGBA_SRGB_BLOCK),
+            DxgiFormat::BC2_UNORM => Some(Format::BC2_UNORM_BLOCK),
+            DxgiFormat::BC2_UNORM_SRGB => Some(Format::BC2_SRGB_BLOCK),
+            DxgiFormat::BC3_UNORM => Some(Format::BC3_UNORM_BLOCK),
+            DxgiFormat::BC3_UNORM_SRGB => Some(Format::BC3_SRGB_BLOCK),
+            DxgiFormat::BC4_UNORM => Some(Format::BC4_UNORM_BLOCK),
+            DxgiFormat::BC4_SNORM => Some(Format::BC4_SNORM_BLOCK),
+            DxgiFormat::BC5_UNORM => Some(Format::BC5_UNORM_BLOCK),
+            DxgiFormat::BC5_SNORM => Some(Format::BC5_SNORM_BLOCK),
+            DxgiFormat::B5G6R5_UNORM => Some(Format::R5G6B5_UNORM_PACK16),
+            DxgiFormat::B5G5R5A1_UNORM => Some(Format::A1R5G5B5_UNORM_PACK16),
+            DxgiFormat::B8G8R8A8_UNORM => Some(Format::B8G8R8A8_UNORM),
+            DxgiFormat::B8G8R8A8_UNORM_SRGB => Some(Format::B8G8R8A8_SRGB),
+            DxgiFormat::BC6H_UF16 => Some(Format::BC6H_UFLOAT_BLOCK),
+            DxgiFormat::BC6H_SF16 => Some(Format::BC6H_SFLOAT_BLOCK),
+            DxgiFormat::BC7_UNORM => Some(Format::BC7_UNORM_BLOCK),
+            DxgiFormat::BC7_UNORM_SRGB => Some(Format::BC7_SRGB_BLOCK),
+            _ => None,
+        }
+    }
+}
+
+impl<Data: AsRef<[u8]>> Reader<Data> {
+    /// Value of the `KTXdxgiFormat__` key, if present and well-formed
+    pub fn dxgi_format(&self) -> Option<DxgiFormat> {
+        DxgiFormat::new(u32::from_le_bytes(self.key_value("KTXdxgiFormat__")?.try_into().ok()?))
+    }
+}
+
+#[test]
+fn test_dxgi_format_round_trip() {
+    let mut count = 0;
+    for &format in Format::ALL {
+        if let Some(dxgi) = format.to_dxgi() {
+            assert_eq!(dxgi.to_format(), Some(format));
+            count += 1;
+        }
+    }
+    assert_eq!(count, 66);
+    assert_eq!(DxgiFormat::BC1_TYPELESS.to_format(), None);
+    assert_eq!(Format::R5G6B5_UNORM_PACK16.to_dxgi(), Some(DxgiFormat::B5G6R5_UNORM));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_read_dxgi_format() {
+    use crate::test_util::{single_level_writer, TEST_TEX};
+
+    let dfd = crate::BasicDataFormatDescriptorBuilder::from_format(Format::R8_UNORM)
+        .unwrap()
+        .build()
+        .unwrap();
+    let value = DxgiFormat::R8_UNORM.0.get().to_le_bytes();
+    let bytes = single_level_writer(Some(Format::R8_UNORM), 1, &dfd, &[0])
+        .key_value("KTXdxgiFormat__", &value)
+        .write()
+        .unwrap();
+
+    assert_eq!(
+        Reader::new(&bytes[..]).unwrap().dxgi_format(),
+        Some(DxgiFormat::R8_UNORM)
+    );
+    assert_eq!(Reader::new(TEST_TEX).unwrap().dxgi_format(), None);
+}
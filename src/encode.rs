@@ -0,0 +1,267 @@
+//! Encoders for the block compressed formats targeted by the transcoders.
+//!
+//! Blocks are given as 16 RGBA pixels in row-major order.
+
+pub(crate) type Pixels = [[u8; 4]; 16];
+
+/// BC7 interpolation weights for 4-bit indices.
+const BC7_WEIGHTS: [u32; 16] = [0, 4, 9, 13, 17, 21, 26, 30, 34, 38, 43, 47, 51, 55, 60, 64];
+
+/// EAC modifier tables, indexed by table index and then by pixel index.
+pub(crate) const EAC_MODIFIERS: [[i32; 8]; 16] = [
+    [-3, -6, -9, -15, 2, 5, 8, 14],
+    [-3, -7, -10, -13, 2, 6, 9, 12],
+    [-2, -5, -8, -13, 1, 4, 7, 12],
+    [-2, -4, -6, -13, 1, 3, 5, 12],
+    [-3, -6, -8, -12, 2, 5, 7, 11],
+    [-3, -7, -9, -11, 2, 6, 8, 10],
+    [-4, -7, -8, -11, 3, 6, 7, 10],
+    [-3, -5, -8, -11, 2, 4, 7, 10],
+    [-2, -6, -8, -10, 1, 5, 7, 9],
+    [-2, -5, -8, -10, 1, 4, 7, 9],
+    [-2, -4, -8, -10, 1, 3, 7, 9],
+    [-2, -5, -7, -10, 1, 4, 6, 9],
+    [-3, -4, -7, -10, 2, 3, 6, 9],
+    [-1, -2, -3, -10, 0, 1, 2, 9],
+    [-4, -6, -8, -9, 3, 5, 7, 8],
+    [-3, -5, -7, -9, 2, 4, 6, 8],
+];
+
+/// Encode a BC1 block from the darkest and brightest pixels. Alpha is ignored.
+pub(crate) fn bc1(pixels: &Pixels) -> [u8; 8] {
+    let luma = |pixel: &&[u8; 4]| pixel[0] as u32 + pixel[1] as u32 + pixel[2] as u32;
+    let min = pixels.iter().min_by_key(luma).unwrap();
+    let max = pixels.iter().max_by_key(luma).unwrap();
+    let (mut color0, mut color1) = (to_rgb565(max), to_rgb565(min));
+
+    let mut bytes = [0; 8];
+    if color0 == color1 {
+        bytes[0..2].copy_from_slice(&color0.to_le_bytes());
+        bytes[2..4].copy_from_slice(&color1.to_le_bytes());
+        return bytes;
+    }
+    // color0 > color1 selects the four color mode
+    if color0 < color1 {
+        core::mem::swap(&mut color0, &mut color1);
+    }
+
+    let (a, b) = (from_rgb565(color0), from_rgb565(color1));
+    let mut palette = [a, b, [0; 3], [0; 3]];
+    for c in 0..3 {
+        palette[2][c] = ((2 * a[c] as u32 + b[c] as u32 + 1) / 3) as u8;
+        palette[3][c] = ((a[c] as u32 + 2 * b[c] as u32 + 1) / 3) as u8;
+    }
+
+    let mut indices = 0u32;
+    for (i, pixel) in pixels.iter().enumerate() {
+        let index = nearest(palette.iter().map(|color| distance(&color[..], &pixel[..3])));
+        indices |= (index as u32) << (2 * i);
+    }
+
+    bytes[0..2].copy_from_slice(&color0.to_le_bytes());
+    bytes[2..4].copy_from_slice(&color1.to_le_bytes());
+    bytes[4..8].copy_from_slice(&indices.to_le_bytes());
+    bytes
+}
+
+/// Encode a single channel BC4 block, as used for BC3 alpha.
+pub(crate) fn bc4(values: &[u8; 16]) -> [u8; 8] {
+    let (min, max) = (*values.iter().min().unwrap(), *values.iter().max().unwrap());
+
+    let mut bytes = [max, min, 0, 0, 0, 0, 0, 0];
+    if min == max {
+        return bytes;
+    }
+
+    let (a, b) = (max as u32, min as u32);
+    let mut palette = [a, b, 0, 0, 0, 0, 0, 0];
+    for (i, value) in palette.iter_mut().enumerate().skip(2) {
+        let weight = i as u32 - 1;
+        *value = ((7 - weight) * a + weight * b + 3) / 7;
+    }
+
+    let mut indices = 0u64;
+    for (i, &value) in values.iter().enumerate() {
+        let index = nearest(palette.iter().map(|&entry| distance(&[entry as u8], &[value])));
+        indices |= (index as u64) << (3 * i);
+    }
+
+    bytes[2..8].copy_from_slice(&indices.to_le_bytes()[..6]);
+    bytes
+}
+
+/// Encode a BC3 block: BC4 alpha followed by BC1 color.
+pub(crate) fn bc3(pixels: &Pixels) -> [u8; 16] {
+    let mut alpha = [0; 16];
+    for (value, pixel) in alpha.iter_mut().zip(pixels) {
+        *value = pixel[3];
+    }
+
+    let mut bytes = [0; 16];
+    bytes[0..8].copy_from_slice(&bc4(&alpha));
+    bytes[8..16].copy_from_slice(&bc1(pixels));
+    bytes
+}
+
+/// Encode a BC7 mode 6 block, which has a single RGBA subset with 4-bit indices.
+pub(crate) fn bc7(pixels: &Pixels) -> [u8; 16] {
+    let mut min = [255; 4];
+    let mut max = [0; 4];
+    for pixel in pixels {
+        for c in 0..4 {
+            min[c] = min[c].min(pixel[c]);
+            max[c] = max[c].max(pixel[c]);
+        }
+    }
+
+    let (mut quantized0, mut p0) = quantize_bc7_endpoint(&min);
+    let (mut quantized1, mut p1) = quantize_bc7_endpoint(&max);
+    let endpoint = |quantized: &[u8; 4], p: u8| quantized.map(|value| ((value << 1) | p) as u32);
+    let (a, b) = (endpoint(&quantized0, p0), endpoint(&quantized1, p1));
+
+    let mut palette = [[0; 4]; 16];
+    for (color, weight) in palette.iter_mut().zip(BC7_WEIGHTS) {
+        for c in 0..4 {
+            color[c] = (((64 - weight) * a[c] + weight * b[c] + 32) >> 6) as u8;
+        }
+    }
+
+    let mut indices = [0; 16];
+    for (index, pixel) in indices.iter_mut().zip(pixels) {
+        *index = nearest(palette.iter().map(|color| distance(color, pixel))) as u8;
+    }
+    // The anchor index is stored without its most significant bit
+    if indices[0] & 8 != 0 {
+        core::mem::swap(&mut quantized0, &mut quantized1);
+        core::mem::swap(&mut p0, &mut p1);
+        for index in &mut indices {
+            *index = 15 - *index;
+        }
+    }
+
+    let mut bits = 1u128 << 6;
+    let mut offset = 7;
+    let mut put = |value: u8, count: u32| {
+        bits |= (value as u128) << offset;
+        offset += count;
+    };
+    for c in 0..4 {
+        put(quantized0[c], 7);
+        put(quantized1[c], 7);
+    }
+    put(p0, 1);
+    put(p1, 1);
+    put(indices[0], 3);
+    for &index in &indices[1..] {
+        put(index, 4);
+    }
+    bits.to_le_bytes()
+}
+
+/// Encode an ETC2 EAC alpha block.
+pub(crate) fn eac_alpha(values: &[u8; 16]) -> [u8; 8] {
+    let (min, max) = (
+        *values.iter().min().unwrap() as i32,
+        *values.iter().max().unwrap() as i32,
+    );
+
+    let mut best = (u32::MAX, 0, 0, 0, [0; 16]);
+    for (table, modifiers) in EAC_MODIFIERS.iter().enumerate() {
+        let (low, high) = (modifiers[3], modifiers[7]);
+        let multiplier = ((max - min + (high - low) / 2) / (high - low)).clamp(1, 15);
+        let base = ((min + max) / 2 - (low + high) * multiplier / 2).clamp(0, 255);
+
+        let mut palette = [0; 8];
+        for (value, modifier) in palette.iter_mut().zip(modifiers) {
+            *value = (base + modifier * multiplier).clamp(0, 255) as u8;
+        }
+
+        let mut error = 0;
+        let mut indices = [0; 16];
+        for (index, &value) in indices.iter_mut().zip(values) {
+            *index = nearest(palette.iter().map(|&entry| distance(&[entry], &[value])));
+            error += distance(&[palette[*index]], &[value]);
+        }
+        if error < best.0 {
+            best = (error, table, multiplier, base, indices);
+        }
+    }
+
+    let (_, table, multiplier, base, indices) = best;
+    // Pixels are stored column-major, starting from the most significant bit
+    let mut bits = 0u64;
+    for x in 0..4 {
+        for y in 0..4 {
+            bits = (bits << 3) | indices[y * 4 + x] as u64;
+        }
+    }
+
+    let mut bytes = [base as u8, ((multiplier as u8) << 4) | table as u8, 0, 0, 0, 0, 0, 0];
+    bytes[2..8].copy_from_slice(&(bits << 16).to_be_bytes()[..6]);
+    bytes
+}
+
+fn quantize_bc7_endpoint(color: &[u8; 4]) -> ([u8; 4], u8) {
+    let mut best = ([0; 4], 0, u32::MAX);
+    for p in 0..2 {
+        let quantized = color.map(|value| ((value as u32 + 1 - p as u32) / 2).min(127) as u8);
+        let error = distance(&quantized.map(|value| (value << 1) | p), color);
+        if error < best.2 {
+            best = (quantized, p, error);
+        }
+    }
+    (best.0, best.1)
+}
+
+fn to_rgb565(pixel: &[u8; 4]) -> u16 {
+    let r = (pixel[0] as u32 * 31 + 127) / 255;
+    let g = (pixel[1] as u32 * 63 + 127) / 255;
+    let b = (pixel[2] as u32 * 31 + 127) / 255;
+    ((r << 11) | (g << 5) | b) as u16
+}
+
+fn from_rgb565(color: u16) -> [u8; 3] {
+    let (r, g, b) = ((color >> 11) as u8, ((color >> 5) & 63) as u8, (color & 31) as u8);
+    [(r << 3) | (r >> 2), (g << 2) | (g >> 4), (b << 3) | (b >> 2)]
+}
+
+fn distance(a: &[u8], b: &[u8]) -> u32 {
+    a.iter()
+        .zip(b)
+        .map(|(&a, &b)| (a as i32 - b as i32).pow(2) as u32)
+        .sum()
+}
+
+fn nearest(distances: impl Iterator<Item = u32>) -> usize {
+    distances
+        .enumerate()
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(index, _)| index)
+        .unwrap()
+}
+
+#[test]
+fn test_encode_solid_blocks() {
+    let pixels = [[254, 0, 0, 128]; 16];
+
+    let bc1 = bc1(&pixels);
+    assert_eq!(u16::from_le_bytes([bc1[0], bc1[1]]), 0xF800);
+
+    let bc3 = bc3(&pixels);
+    assert_eq!(&bc3[0..2], &[128, 128]);
+
+    // Mode 6, with every channel's endpoints reconstructing exactly
+    let bc7 = u128::from_le_bytes(bc7(&pixels));
+    assert_eq!(bc7 & 0x7F, 1 << 6);
+    let endpoint = |channel: u32, endpoint: u32| ((bc7 >> (7 + 14 * channel + 7 * endpoint)) & 0x7F) as u8;
+    let p = |endpoint: u32| ((bc7 >> (63 + endpoint)) & 1) as u8;
+    for (channel, &value) in pixels[0].iter().enumerate() {
+        assert_eq!((endpoint(channel as u32, 0) << 1) | p(0), value);
+        assert_eq!((endpoint(channel as u32, 1) << 1) | p(1), value);
+    }
+
+    let eac = eac_alpha(&[128; 16]);
+    let (base, multiplier, table) = (eac[0] as i32, (eac[1] >> 4) as i32, (eac[1] & 15) as usize);
+    let first_index = (eac[2] >> 5) as usize;
+    assert_eq!(base + EAC_MODIFIERS[table][first_index] * multiplier, 128);
+}
@@ -14,6 +14,10 @@ macro_rules! pseudo_enum {
             $(
                 pub const $case: Self = Self(unsafe { NonZeroU32::new_unchecked($value) });
             )*
+
+            /// All known values, in declaration order
+            #[allow(dead_code)]
+            pub(crate) const ALL: &'static [Self] = &[$(Self::$case,)*];
         }
 
         impl fmt::Debug for $name {
@@ -204,6 +208,275 @@ pseudo_enum! {
     }
 }
 
+pseudo_enum! {
+    /// Direct3D `DXGI_FORMAT` values, without the `DXGI_FORMAT_` prefix. `DXGI_FORMAT_420_OPAQUE` is named
+    /// `OPAQUE_420`.
+    DxgiFormat {
+        R32G32B32A32_TYPELESS = 1,
+        R32G32B32A32_FLOAT = 2,
+        R32G32B32A32_UINT = 3,
+        R32G32B32A32_SINT = 4,
+        R32G32B32_TYPELESS = 5,
+        R32G32B32_FLOAT = 6,
+        R32G32B32_UINT = 7,
+        R32G32B32_SINT = 8,
+        R16G16B16A16_TYPELESS = 9,
+        R16G16B16A16_FLOAT = 10,
+        R16G16B16A16_UNORM = 11,
+        R16G16B16A16_UINT = 12,
+        R16G16B16A16_SNORM = 13,
+        R16G16B16A16_SINT = 14,
+        R32G32_TYPELESS = 15,
+        R32G32_FLOAT = 16,
+        R32G32_UINT = 17,
+        R32G32_SINT = 18,
+        R32G8X24_TYPELESS = 19,
+        D32_FLOAT_S8X24_UINT = 20,
+        R32_FLOAT_X8X24_TYPELESS = 21,
+        X32_TYPELESS_G8X24_UINT = 22,
+        R10G10B10A2_TYPELESS = 23,
+        R10G10B10A2_UNORM = 24,
+        R10G10B10A2_UINT = 25,
+        R11G11B10_FLOAT = 26,
+        R8G8B8A8_TYPELESS = 27,
+        R8G8B8A8_UNORM = 28,
+        R8G8B8A8_UNORM_SRGB = 29,
+        R8G8B8A8_UINT = 30,
+        R8G8B8A8_SNORM = 31,
+        R8G8B8A8_SINT = 32,
+        R16G16_TYPELESS = 33,
+        R16G16_FLOAT = 34,
+        R16G16_UNORM = 35,
+        R16G16_UINT = 36,
+        R16G16_SNORM = 37,
+        R16G16_SINT = 38,
+        R32_TYPELESS = 39,
+        D32_FLOAT = 40,
+        R32_FLOAT = 41,
+        R32_UINT = 42,
+        R32_SINT = 43,
+        R24G8_TYPELESS = 44,
+        D24_UNORM_S8_UINT = 45,
+        R24_UNORM_X8_TYPELESS = 46,
+        X24_TYPELESS_G8_UINT = 47,
+        R8G8_TYPELESS = 48,
+        R8G8_UNORM = 49,
+        R8G8_UINT = 50,
+        R8G8_SNORM = 51,
+        R8G8_SINT = 52,
+        R16_TYPELESS = 53,
+        R16_FLOAT = 54,
+        D16_UNORM = 55,
+        R16_UNORM = 56,
+        R16_UINT = 57,
+        R16_SNORM = 58,
+        R16_SINT = 59,
+        R8_TYPELESS = 60,
+        R8_UNORM = 61,
+        R8_UINT = 62,
+        R8_SNORM = 63,
+        R8_SINT = 64,
+        A8_UNORM = 65,
+        R1_UNORM = 66,
+        R9G9B9E5_SHAREDEXP = 67,
+        R8G8_B8G8_UNORM = 68,
+        G8R8_G8B8_UNORM = 69,
+        BC1_TYPELESS = 70,
+        BC1_UNORM = 71,
+        BC1_UNORM_SRGB = 72,
+        BC2_TYPELESS = 73,
+        BC2_UNORM = 74,
+        BC2_UNORM_SRGB = 75,
+        BC3_TYPELESS = 76,
+        BC3_UNORM = 77,
+        BC3_UNORM_SRGB = 78,
+        BC4_TYPELESS = 79,
+        BC4_UNORM = 80,
+        BC4_SNORM = 81,
+        BC5_TYPELESS = 82,
+        BC5_UNORM = 83,
+        BC5_SNORM = 84,
+        B5G6R5_UNORM = 85,
+        B5G5R5A1_UNORM = 86,
+        B8G8R8A8_UNORM = 87,
+        B8G8R8X8_UNORM = 88,
+        R10G10B10_XR_BIAS_A2_UNORM = 89,
+        B8G8R8A8_TYPELESS = 90,
+        B8G8R8A8_UNORM_SRGB = 91,
+        B8G8R8X8_TYPELESS = 92,
+        B8G8R8X8_UNORM_SRGB = 93,
+        BC6H_TYPELESS = 94,
+        BC6H_UF16 = 95,
+        BC6H_SF16 = 96,
+        BC7_TYPELESS = 97,
+        BC7_UNORM = 98,
+        BC7_UNORM_SRGB = 99,
+        AYUV = 100,
+        Y410 = 101,
+        Y416 = 102,
+        NV12 = 103,
+        P010 = 104,
+        P016 = 105,
+        OPAQUE_420 = 106,
+        YUY2 = 107,
+        Y210 = 108,
+        Y216 = 109,
+        NV11 = 110,
+        AI44 = 111,
+        IA44 = 112,
+        P8 = 113,
+        A8P8 = 114,
+        B4G4R4A4_UNORM = 115,
+        P208 = 130,
+        V208 = 131,
+        V408 = 132,
+    }
+}
+
+pseudo_enum! {
+    /// Metal `MTLPixelFormat` values, without the `MTLPixelFormat` prefix
+    MetalPixelFormat {
+        A8Unorm = 1,
+        R8Unorm = 10,
+        R8Unorm_sRGB = 11,
+        R8Snorm = 12,
+        R8Uint = 13,
+        R8Sint = 14,
+        R16Unorm = 20,
+        R16Snorm = 22,
+        R16Uint = 23,
+        R16Sint = 24,
+        R16Float = 25,
+        RG8Unorm = 30,
+        RG8Unorm_sRGB = 31,
+        RG8Snorm = 32,
+        RG8Uint = 33,
+        RG8Sint = 34,
+        B5G6R5Unorm = 40,
+        A1BGR5Unorm = 41,
+        ABGR4Unorm = 42,
+        BGR5A1Unorm = 43,
+        R32Uint = 53,
+        R32Sint = 54,
+        R32Float = 55,
+        RG16Unorm = 60,
+        RG16Snorm = 62,
+        RG16Uint = 63,
+        RG16Sint = 64,
+        RG16Float = 65,
+        RGBA8Unorm = 70,
+        RGBA8Unorm_sRGB = 71,
+        RGBA8Snorm = 72,
+        RGBA8Uint = 73,
+        RGBA8Sint = 74,
+        BGRA8Unorm = 80,
+        BGRA8Unorm_sRGB = 81,
+        RGB10A2Unorm = 90,
+        RGB10A2Uint = 91,
+        RG11B10Float = 92,
+        RGB9E5Float = 93,
+        BGR10A2Unorm = 94,
+        RG32Uint = 103,
+        RG32Sint = 104,
+        RG32Float = 105,
+        RGBA16Unorm = 110,
+        RGBA16Snorm = 112,
+        RGBA16Uint = 113,
+        RGBA16Sint = 114,
+        RGBA16Float = 115,
+        RGBA32Uint = 123,
+        RGBA32Sint = 124,
+        RGBA32Float = 125,
+        BC1_RGBA = 130,
+        BC1_RGBA_sRGB = 131,
+        BC2_RGBA = 132,
+        BC2_RGBA_sRGB = 133,
+        BC3_RGBA = 134,
+        BC3_RGBA_sRGB = 135,
+        BC4_RUnorm = 140,
+        BC4_RSnorm = 141,
+        BC5_RGUnorm = 142,
+        BC5_RGSnorm = 143,
+        BC6H_RGBFloat = 150,
+        BC6H_RGBUfloat = 151,
+        BC7_RGBAUnorm = 152,
+        BC7_RGBAUnorm_sRGB = 153,
+        PVRTC_RGB_2BPP = 160,
+        PVRTC_RGB_2BPP_sRGB = 161,
+        PVRTC_RGB_4BPP = 162,
+        PVRTC_RGB_4BPP_sRGB = 163,
+        PVRTC_RGBA_2BPP = 164,
+        PVRTC_RGBA_2BPP_sRGB = 165,
+        PVRTC_RGBA_4BPP = 166,
+        PVRTC_RGBA_4BPP_sRGB = 167,
+        EAC_R11Unorm = 170,
+        EAC_R11Snorm = 172,
+        EAC_RG11Unorm = 174,
+        EAC_RG11Snorm = 176,
+        EAC_RGBA8 = 178,
+        EAC_RGBA8_sRGB = 179,
+        ETC2_RGB8 = 180,
+        ETC2_RGB8_sRGB = 181,
+        ETC2_RGB8A1 = 182,
+        ETC2_RGB8A1_sRGB = 183,
+        ASTC_4x4_sRGB = 186,
+        ASTC_5x4_sRGB = 187,
+        ASTC_5x5_sRGB = 188,
+        ASTC_6x5_sRGB = 189,
+        ASTC_6x6_sRGB = 190,
+        ASTC_8x5_sRGB = 192,
+        ASTC_8x6_sRGB = 193,
+        ASTC_8x8_sRGB = 194,
+        ASTC_10x5_sRGB = 195,
+        ASTC_10x6_sRGB = 196,
+        ASTC_10x8_sRGB = 197,
+        ASTC_10x10_sRGB = 198,
+        ASTC_12x10_sRGB = 199,
+        ASTC_12x12_sRGB = 200,
+        ASTC_4x4_LDR = 204,
+        ASTC_5x4_LDR = 205,
+        ASTC_5x5_LDR = 206,
+        ASTC_6x5_LDR = 207,
+        ASTC_6x6_LDR = 208,
+        ASTC_8x5_LDR = 210,
+        ASTC_8x6_LDR = 211,
+        ASTC_8x8_LDR = 212,
+        ASTC_10x5_LDR = 213,
+        ASTC_10x6_LDR = 214,
+        ASTC_10x8_LDR = 215,
+        ASTC_10x10_LDR = 216,
+        ASTC_12x10_LDR = 217,
+        ASTC_12x12_LDR = 218,
+        ASTC_4x4_HDR = 222,
+        ASTC_5x4_HDR = 223,
+        ASTC_5x5_HDR = 224,
+        ASTC_6x5_HDR = 225,
+        ASTC_6x6_HDR = 226,
+        ASTC_8x5_HDR = 228,
+        ASTC_8x6_HDR = 229,
+        ASTC_8x8_HDR = 230,
+        ASTC_10x5_HDR = 231,
+        ASTC_10x6_HDR = 232,
+        ASTC_10x8_HDR = 233,
+        ASTC_10x10_HDR = 234,
+        ASTC_12x10_HDR = 235,
+        ASTC_12x12_HDR = 236,
+        GBGR422 = 240,
+        BGRG422 = 241,
+        Depth16Unorm = 250,
+        Depth32Float = 252,
+        Stencil8 = 253,
+        Depth24Unorm_Stencil8 = 255,
+        Depth32Float_Stencil8 = 260,
+        X32_Stencil8 = 261,
+        X24_Stencil8 = 262,
+        BGRA10_XR = 552,
+        BGRA10_XR_sRGB = 553,
+        BGR10_XR = 554,
+        BGR10_XR_sRGB = 555,
+    }
+}
+
 pseudo_enum! {
     /// Known supercompression schemes
     SupercompressionScheme {
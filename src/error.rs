@@ -1,6 +1,7 @@
+use crate::{ColorModel, SupercompressionScheme};
 use core::fmt;
 #[cfg(feature = "std")]
-use std::error::Error;
+use std::{error::Error, io};
 
 /// Error, that happend when data doesn't satisfy expected parameters.
 #[derive(Debug)]
@@ -14,6 +15,8 @@ pub enum ParseError {
     ZeroFaceCount,
     /// Unexpected end of buffer
     UnexpectedEnd,
+    /// Supercompression global data is longer than its sections
+    InvalidSupercompressionGlobalData,
 }
 
 #[cfg(feature = "std")]
@@ -26,6 +29,246 @@ impl fmt::Display for ParseError {
             ParseError::ZeroWidth => f.pad("zero pixel width"),
             ParseError::ZeroFaceCount => f.pad("zero face count"),
             ParseError::UnexpectedEnd => f.pad("unexpected end of buffer"),
+            ParseError::InvalidSupercompressionGlobalData => f.pad("invalid supercompression global data"),
         }
     }
 }
+
+/// Error, that happend when the data given to a [`Writer`](crate::Writer) can't be serialized.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WriteError {
+    /// Number of levels doesn't match the header's level count
+    LevelCountMismatch,
+    /// Data format descriptor is empty or not 4-byte aligned
+    InvalidDataFormatDescriptor,
+    /// Level length differs from its uncompressed length without supercompression
+    UncompressedLengthMismatch,
+    /// Key is empty or contains a NUL character
+    InvalidKey,
+    /// Key appears more than once
+    DuplicateKey,
+    /// Section offsets don't fit into the index
+    Overflow,
+    /// Levels can't be compressed with the header's supercompression scheme
+    UnsupportedSupercompressionScheme,
+}
+
+#[cfg(feature = "std")]
+impl Error for WriteError {}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            WriteError::LevelCountMismatch => f.pad("level count mismatch"),
+            WriteError::InvalidDataFormatDescriptor => f.pad("invalid data format descriptor"),
+            WriteError::UncompressedLengthMismatch => f.pad("uncompressed byte length mismatch"),
+            WriteError::InvalidKey => f.pad("invalid key"),
+            WriteError::DuplicateKey => f.pad("duplicate key"),
+            WriteError::Overflow => f.pad("section offset overflow"),
+            WriteError::UnsupportedSupercompressionScheme => f.pad("unsupported supercompression scheme"),
+        }
+    }
+}
+
+/// Error, that happend when no [`Format`](crate::Format) matches a data format descriptor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatInferenceError {
+    /// The color model has no corresponding format. None means Unspecified
+    UnsupportedColorModel(Option<ColorModel>),
+    /// The color model is supported, but no format has this texel block layout and samples
+    NoMatchingFormat,
+}
+
+#[cfg(feature = "std")]
+impl Error for FormatInferenceError {}
+
+impl fmt::Display for FormatInferenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            FormatInferenceError::UnsupportedColorModel(Some(model)) => {
+                write!(f, "color model {:?} has no corresponding format", model)
+            }
+            FormatInferenceError::UnsupportedColorModel(None) => f.pad("unspecified color model"),
+            FormatInferenceError::NoMatchingFormat => f.pad("no format matches the descriptor"),
+        }
+    }
+}
+
+/// Error, that happend when a level's supercompressed data can't be decompressed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecompressionError {
+    /// The scheme isn't supported, or the cargo feature that decodes it isn't enabled
+    UnsupportedScheme(SupercompressionScheme),
+    /// The supercompressed data is malformed
+    InvalidData,
+    /// Decompressed length differs from the level's uncompressed byte length
+    LengthMismatch,
+}
+
+#[cfg(feature = "std")]
+impl Error for DecompressionError {}
+
+impl fmt::Display for DecompressionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            DecompressionError::UnsupportedScheme(scheme) => {
+                write!(f, "unsupported supercompression scheme {:?}", scheme)
+            }
+            DecompressionError::InvalidData => f.pad("invalid supercompressed data"),
+            DecompressionError::LengthMismatch => f.pad("uncompressed byte length mismatch"),
+        }
+    }
+}
+
+/// Error, that happend when an image can't be transcoded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TranscodeError {
+    /// The texture isn't encoded in the format the transcoder expects
+    UnsupportedTexture,
+    /// Global data or image data is malformed
+    InvalidData,
+    /// Images predicted from the previous frame aren't supported
+    UnsupportedPFrame,
+}
+
+#[cfg(feature = "std")]
+impl Error for TranscodeError {}
+
+impl fmt::Display for TranscodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            TranscodeError::UnsupportedTexture => f.pad("unsupported texture"),
+            TranscodeError::InvalidData => f.pad("invalid image data"),
+            TranscodeError::UnsupportedPFrame => f.pad("unsupported p-frame"),
+        }
+    }
+}
+
+/// Error, that happend when a format has no equivalent in another graphics API.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatConversionError {
+    /// The format has no equivalent
+    Unsupported,
+    /// The header's format is undefined, as for Basis Universal textures
+    Undefined,
+    /// The number of array layers, counting each cubemap face, overflows
+    LayerCountOverflow,
+}
+
+#[cfg(feature = "std")]
+impl Error for FormatConversionError {}
+
+impl fmt::Display for FormatConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            FormatConversionError::Unsupported => f.pad("format has no equivalent"),
+            FormatConversionError::Undefined => f.pad("undefined format"),
+            FormatConversionError::LayerCountOverflow => f.pad("layer count overflows"),
+        }
+    }
+}
+
+/// Error, that happend when a chunk is pushed to a [`ProgressiveReader`](crate::ProgressiveReader).
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ChunkError {
+    /// The chunk was already pushed, or its range is not known yet
+    Unexpected,
+    /// The chunk's length differs from the length of its range
+    LengthMismatch { expected: u64 },
+    /// The chunk is malformed
+    Parse(ParseError),
+}
+
+#[cfg(all(feature = "alloc", feature = "std"))]
+impl Error for ChunkError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ChunkError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ChunkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            ChunkError::Unexpected => f.pad("unexpected chunk"),
+            ChunkError::LengthMismatch { expected } => {
+                write!(f, "chunk length mismatch, expected {} bytes", expected)
+            }
+            ChunkError::Parse(error) => write!(f, "parse error: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<ParseError> for ChunkError {
+    fn from(error: ParseError) -> Self {
+        ChunkError::Parse(error)
+    }
+}
+
+/// Error, that happend while reading a texture from a stream.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum StreamError {
+    /// The underlying stream failed
+    Io(io::Error),
+    /// The texture is malformed
+    Parse(ParseError),
+    /// A level, layer, face or z slice index is out of range
+    OutOfRange,
+    /// Images can't be read from supercompressed levels
+    Supercompressed,
+    /// The buffer's length differs from the length of the data being read
+    BufferLengthMismatch { expected: u64 },
+}
+
+#[cfg(feature = "std")]
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(error) => Some(error),
+            StreamError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            StreamError::Io(error) => write!(f, "i/o error: {}", error),
+            StreamError::Parse(error) => write!(f, "parse error: {}", error),
+            StreamError::OutOfRange => f.pad("index out of range"),
+            StreamError::Supercompressed => f.pad("level is supercompressed"),
+            StreamError::BufferLengthMismatch { expected } => {
+                write!(f, "buffer length mismatch, expected {} bytes", expected)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> Self {
+        StreamError::Parse(error)
+    }
+}
@@ -0,0 +1,1275 @@
+use crate::{
+    basis::{image_count, BasisLZGlobalData, BasisLZImageDescription, BasisLZImageFlags},
+    encode,
+    error::TranscodeError,
+    Reader,
+};
+use alloc::{vec, vec::Vec};
+
+/// Order in which code length code sizes are stored.
+const CODE_LENGTH_CODE_ORDER: [usize; 21] = [17, 18, 19, 20, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15, 16];
+const MAX_CODE_SIZE: usize = 16;
+
+const ENDPOINT_PRED_REPEAT_LAST_SYMBOL: u32 = 256;
+const ENDPOINT_PRED_MIN_REPEAT_COUNT: u32 = 3;
+const ENDPOINT_PRED_COUNT_VLC_BITS: u32 = 4;
+const SELECTOR_HISTORY_BUF_RLE_COUNT_THRESH: u32 = 3;
+const SELECTOR_HISTORY_BUF_RLE_COUNT_TOTAL: u32 = 64;
+/// Upper bound of the blocks a slice can describe per bit. Every endpoint prediction symbol takes at least one
+/// bit, and the longest repeat, with its run length of at least five bits, covers fewer than 2^32 + 3 groups of
+/// four blocks.
+const MAX_BLOCKS_PER_SLICE_BIT: u64 = 1 << 32;
+
+/// ETC1 intensity modifiers, sorted from the most negative to the most positive.
+const INTENSITY_TABLES: [[i32; 4]; 8] = [
+    [-8, -2, 2, 8],
+    [-17, -5, 5, 17],
+    [-29, -9, 9, 29],
+    [-42, -13, 13, 42],
+    [-60, -18, 18, 60],
+    [-80, -24, 24, 80],
+    [-106, -33, 33, 106],
+    [-183, -47, 47, 183],
+];
+/// ETC1 pixel index for each selector.
+const ETC1_SELECTORS: [u8; 4] = [3, 2, 0, 1];
+
+/// Block format produced by a transcoder.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TranscodeTarget {
+    /// Uncompressed 8-bit RGBA pixels in row-major order
+    Rgba8,
+    /// BC1 RGB blocks, ignoring alpha
+    Bc1,
+    /// BC3 RGBA blocks
+    Bc3,
+    /// BC7 RGBA blocks
+    Bc7,
+    /// ETC2 RGBA blocks, with EAC alpha
+    Etc2Rgba,
+}
+
+/// Transcoder for ETC1S images in BasisLZ supercompressed textures.
+///
+/// ```rust
+/// # fn transcode(file: &[u8]) -> Result<(), ktx2::TranscodeError> {
+/// let reader = ktx2::Reader::new(file).unwrap();
+/// for image in reader.transcode_etc1s(ktx2::TranscodeTarget::Bc7)? {
+///     // Upload image
+/// #   let _ = image;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Etc1sTranscoder {
+    endpoints: Vec<Endpoint>,
+    /// Two-bit selectors, one byte per row
+    selectors: Vec<[u8; 4]>,
+    tables: SliceTables,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Endpoint {
+    color5: [u8; 3],
+    intensity: u8,
+}
+
+#[derive(Clone, Debug)]
+struct SliceTables {
+    endpoint_pred: HuffmanTable,
+    delta_endpoint: HuffmanTable,
+    selector: HuffmanTable,
+    selector_history_rle: HuffmanTable,
+    selector_history_size: usize,
+}
+
+/// Endpoint and selector palette indices of a decoded block.
+#[derive(Copy, Clone)]
+struct Block {
+    endpoint: usize,
+    selector: usize,
+}
+
+impl Etc1sTranscoder {
+    /// Decode the codebooks and Huffman tables of the global data.
+    pub fn new(global_data: &BasisLZGlobalData<'_>) -> Result<Self, TranscodeError> {
+        let endpoints = decode_endpoints(global_data.endpoints(), global_data.header.endpoint_count as usize)?;
+        let selectors = decode_selectors(global_data.selectors(), global_data.header.selector_count as usize)?;
+        let tables = decode_tables(global_data.tables())?;
+        Ok(Self {
+            endpoints,
+            selectors,
+            tables,
+        })
+    }
+
+    /// Transcode one image of `width` by `height` pixels.
+    ///
+    /// `level_data` is the data of the level containing the image, which the slice offsets of `description`
+    /// refer to. Block formats are returned as blocks in row-major order.
+    pub fn transcode_image(
+        &self,
+        description: &BasisLZImageDescription,
+        level_data: &[u8],
+        width: u32,
+        height: u32,
+        target: TranscodeTarget,
+    ) -> Result<Vec<u8>, TranscodeError> {
+        if description.image_flags.contains(BasisLZImageFlags::IS_P_FRAME) {
+            return Err(TranscodeError::UnsupportedPFrame);
+        }
+
+        let slice = |offset: u32, length: u32| {
+            let end = (offset as usize)
+                .checked_add(length as usize)
+                .ok_or(TranscodeError::InvalidData)?;
+            level_data.get(offset as usize..end).ok_or(TranscodeError::InvalidData)
+        };
+        let blocks_x = width.div_ceil(4) as usize;
+        let blocks_y = height.div_ceil(4) as usize;
+        let block_count = blocks_x.checked_mul(blocks_y).ok_or(TranscodeError::InvalidData)?;
+        let rgb = self.decode_slice(
+            slice(description.rgb_slice_byte_offset, description.rgb_slice_byte_length)?,
+            blocks_x,
+            blocks_y,
+        )?;
+        let alpha = if description.alpha_slice_byte_length != 0 {
+            Some(self.decode_slice(
+                slice(description.alpha_slice_byte_offset, description.alpha_slice_byte_length)?,
+                blocks_x,
+                blocks_y,
+            )?)
+        } else {
+            None
+        };
+
+        let block_alpha = |index: usize| {
+            let mut values = [255; 16];
+            if let Some(alpha) = &alpha {
+                // Alpha slices are grayscale, so any channel will do
+                for (value, pixel) in values.iter_mut().zip(self.pixels(alpha[index])) {
+                    *value = pixel[1];
+                }
+            }
+            values
+        };
+        let block_pixels = |index: usize| {
+            let mut pixels = self.pixels(rgb[index]);
+            for (pixel, alpha) in pixels.iter_mut().zip(block_alpha(index)) {
+                pixel[3] = alpha;
+            }
+            pixels
+        };
+
+        let output_length = match target {
+            TranscodeTarget::Rgba8 => (width as usize)
+                .checked_mul(height as usize)
+                .and_then(|pixels| pixels.checked_mul(4)),
+            TranscodeTarget::Bc1 => block_count.checked_mul(8),
+            _ => block_count.checked_mul(16),
+        }
+        .ok_or(TranscodeError::InvalidData)?;
+        let mut output = Vec::new();
+        output
+            .try_reserve_exact(output_length)
+            .map_err(|_| TranscodeError::InvalidData)?;
+        match target {
+            TranscodeTarget::Rgba8 => {
+                output.resize(output_length, 0);
+                let (width, height) = (width as usize, height as usize);
+                for (index, pixels) in (0..rgb.len()).map(block_pixels).enumerate() {
+                    let (block_x, block_y) = (index % blocks_x * 4, index / blocks_x * 4);
+                    // Rows of the block, clipped at the right and bottom edges
+                    let columns = (width - block_x).min(4);
+                    for (y, row) in pixels.chunks_exact(4).take(height - block_y).enumerate() {
+                        let offset = ((block_y + y) * width + block_x) * 4;
+                        for (target, pixel) in output[offset..offset + columns * 4].chunks_exact_mut(4).zip(row) {
+                            target.copy_from_slice(pixel);
+                        }
+                    }
+                }
+            }
+            TranscodeTarget::Bc1 => {
+                for &block in &rgb {
+                    output.extend_from_slice(&encode::bc1(&self.pixels(block)));
+                }
+            }
+            TranscodeTarget::Bc3 => {
+                for index in 0..rgb.len() {
+                    output.extend_from_slice(&encode::bc3(&block_pixels(index)));
+                }
+            }
+            TranscodeTarget::Bc7 => {
+                for index in 0..rgb.len() {
+                    output.extend_from_slice(&encode::bc7(&block_pixels(index)));
+                }
+            }
+            TranscodeTarget::Etc2Rgba => {
+                for (index, &block) in rgb.iter().enumerate() {
+                    output.extend_from_slice(&encode::eac_alpha(&block_alpha(index)));
+                    output.extend_from_slice(&self.etc1(block));
+                }
+            }
+        }
+        Ok(output)
+    }
+
+    fn decode_slice(&self, data: &[u8], blocks_x: usize, blocks_y: usize) -> Result<Vec<Block>, TranscodeError> {
+        let block_count = blocks_x.checked_mul(blocks_y).ok_or(TranscodeError::InvalidData)?;
+        if block_count as u64 > (data.len() as u64 * 8).saturating_mul(MAX_BLOCKS_PER_SLICE_BIT) {
+            return Err(TranscodeError::InvalidData);
+        }
+        let tables = &self.tables;
+        let endpoint_count = self.endpoints.len();
+        let selector_count = self.selectors.len();
+        let history_first_symbol = selector_count as u32;
+        let history_rle_symbol = history_first_symbol + tables.selector_history_size as u32;
+
+        let mut bits = BitReader::new(data);
+        let mut history = SelectorHistory::new(tables.selector_history_size);
+        let mut blocks = filled(
+            Block {
+                endpoint: 0,
+                selector: 0,
+            },
+            block_count,
+        )?;
+        // Prediction bits of the lower two blocks of each 2x2 group, stored while decoding the upper row
+        let mut lower_pred_bits = filled(0, blocks_x)?;
+
+        let mut pred_bits = 0;
+        let mut previous_pred_symbol = 0;
+        let mut pred_repeat_count = 0;
+        let mut previous_endpoint = 0;
+        let mut selector_rle_count = 0;
+
+        for y in 0..blocks_y {
+            for x in 0..blocks_x {
+                if x & 1 == 0 {
+                    if y & 1 == 0 {
+                        if pred_repeat_count > 0 {
+                            pred_repeat_count -= 1;
+                            pred_bits = previous_pred_symbol;
+                        } else {
+                            pred_bits = bits.symbol(&tables.endpoint_pred)?;
+                            if pred_bits == ENDPOINT_PRED_REPEAT_LAST_SYMBOL {
+                                pred_repeat_count =
+                                    bits.vlc(ENDPOINT_PRED_COUNT_VLC_BITS) + ENDPOINT_PRED_MIN_REPEAT_COUNT - 1;
+                                pred_bits = previous_pred_symbol;
+                            } else {
+                                previous_pred_symbol = pred_bits;
+                            }
+                        }
+                        lower_pred_bits[x] = pred_bits >> 4;
+                    } else {
+                        pred_bits = lower_pred_bits[x];
+                    }
+                }
+
+                let pred = pred_bits & 3;
+                pred_bits >>= 2;
+                let endpoint = match pred {
+                    // Left
+                    0 if x > 0 => previous_endpoint,
+                    // Upper
+                    1 if y > 0 => blocks[(y - 1) * blocks_x + x].endpoint,
+                    // Upper left
+                    2 if x > 0 && y > 0 => blocks[(y - 1) * blocks_x + x - 1].endpoint,
+                    3 => {
+                        let endpoint = previous_endpoint + bits.symbol(&tables.delta_endpoint)? as usize;
+                        if endpoint >= endpoint_count {
+                            endpoint - endpoint_count
+                        } else {
+                            endpoint
+                        }
+                    }
+                    _ => return Err(TranscodeError::InvalidData),
+                };
+                previous_endpoint = endpoint;
+
+                let selector_symbol = if selector_rle_count > 0 {
+                    selector_rle_count -= 1;
+                    history_first_symbol
+                } else {
+                    let symbol = bits.symbol(&tables.selector)?;
+                    if symbol == history_rle_symbol {
+                        let run = bits.symbol(&tables.selector_history_rle)?;
+                        selector_rle_count = if run == SELECTOR_HISTORY_BUF_RLE_COUNT_TOTAL - 1 {
+                            bits.vlc(7) + SELECTOR_HISTORY_BUF_RLE_COUNT_THRESH
+                        } else {
+                            run + SELECTOR_HISTORY_BUF_RLE_COUNT_THRESH
+                        };
+                        if selector_rle_count as usize > blocks.len() {
+                            return Err(TranscodeError::InvalidData);
+                        }
+                        selector_rle_count -= 1;
+                        history_first_symbol
+                    } else {
+                        symbol
+                    }
+                };
+                let selector = if selector_symbol >= history_first_symbol {
+                    let index = (selector_symbol - history_first_symbol) as usize;
+                    let selector = history.get(index).ok_or(TranscodeError::InvalidData)?;
+                    history.use_index(index);
+                    selector
+                } else {
+                    history.add(selector_symbol as usize);
+                    selector_symbol as usize
+                };
+
+                if endpoint >= endpoint_count || selector >= selector_count {
+                    return Err(TranscodeError::InvalidData);
+                }
+                blocks[y * blocks_x + x] = Block { endpoint, selector };
+            }
+        }
+
+        Ok(blocks)
+    }
+
+    fn pixels(&self, block: Block) -> encode::Pixels {
+        let endpoint = self.endpoints[block.endpoint];
+        let selectors = self.selectors[block.selector];
+
+        let mut colors = [[0; 4]; 4];
+        for (color, modifier) in colors.iter_mut().zip(INTENSITY_TABLES[endpoint.intensity as usize]) {
+            for (value, color5) in color.iter_mut().zip(endpoint.color5) {
+                let base = ((color5 << 3) | (color5 >> 2)) as i32;
+                *value = (base + modifier).clamp(0, 255) as u8;
+            }
+            color[3] = 255;
+        }
+
+        let mut pixels = [[0; 4]; 16];
+        for (i, pixel) in pixels.iter_mut().enumerate() {
+            *pixel = colors[((selectors[i / 4] >> (2 * (i % 4))) & 3) as usize];
+        }
+        pixels
+    }
+
+    /// The block as an ETC1 block in differential mode with identical subblocks.
+    fn etc1(&self, block: Block) -> [u8; 8] {
+        let endpoint = self.endpoints[block.endpoint];
+        let selectors = self.selectors[block.selector];
+
+        let mut msb = 0u16;
+        let mut lsb = 0u16;
+        for y in 0..4 {
+            for x in 0..4 {
+                let index = ETC1_SELECTORS[((selectors[y] >> (2 * x)) & 3) as usize];
+                // Pixel indices are stored column-major
+                let bit = x * 4 + y;
+                msb |= ((index >> 1) as u16) << bit;
+                lsb |= ((index & 1) as u16) << bit;
+            }
+        }
+
+        let mut bytes = [0; 8];
+        for (byte, color5) in bytes.iter_mut().zip(endpoint.color5) {
+            *byte = color5 << 3;
+        }
+        // Both intensity tables, the differential bit and the flip bit
+        bytes[3] = (endpoint.intensity << 5) | (endpoint.intensity << 2) | 0b11;
+        bytes[4..6].copy_from_slice(&msb.to_be_bytes());
+        bytes[6..8].copy_from_slice(&lsb.to_be_bytes());
+        bytes
+    }
+}
+
+impl<Data: AsRef<[u8]>> Reader<Data> {
+    /// Transcode every image of an ETC1S texture.
+    ///
+    /// Images are returned in the order of the BasisLZ image descriptions: by level, then layer, then face,
+    /// then z slice.
+    pub fn transcode_etc1s(&self, target: TranscodeTarget) -> Result<Vec<Vec<u8>>, TranscodeError> {
+        let global_data = self
+            .basis_lz_global_data()
+            .ok_or(TranscodeError::UnsupportedTexture)?
+            .map_err(|_| TranscodeError::InvalidData)?;
+        let transcoder = Etc1sTranscoder::new(&global_data)?;
+
+        let header = self.header();
+        let mut descriptions = global_data.image_descriptions();
+        let mut images = Vec::new();
+        images
+            .try_reserve_exact(image_count(&header).map_err(|_| TranscodeError::InvalidData)?)
+            .map_err(|_| TranscodeError::InvalidData)?;
+        for (level_index, level) in self.levels().enumerate() {
+            let extent = |size: u32| size.checked_shr(level_index as u32).unwrap_or(0).max(1);
+            let (width, height, depth) = (
+                extent(header.pixel_width),
+                extent(header.pixel_height),
+                extent(header.pixel_depth),
+            );
+            let level_images = header
+                .layer_count
+                .max(1)
+                .checked_mul(header.face_count)
+                .and_then(|images| images.checked_mul(depth))
+                .ok_or(TranscodeError::InvalidData)?;
+            for _ in 0..level_images {
+                let description = descriptions.next().ok_or(TranscodeError::InvalidData)?;
+                images.push(transcoder.transcode_image(&description, level.data, width, height, target)?);
+            }
+        }
+        Ok(images)
+    }
+}
+
+fn decode_endpoints(data: &[u8], count: usize) -> Result<Vec<Endpoint>, TranscodeError> {
+    let mut bits = BitReader::new(data);
+    let color5_delta_models = [bits.huffman_table()?, bits.huffman_table()?, bits.huffman_table()?];
+    let intensity_delta_model = bits.huffman_table()?;
+    if color5_delta_models.iter().any(HuffmanTable::is_empty) || intensity_delta_model.is_empty() {
+        return Err(TranscodeError::InvalidData);
+    }
+    let grayscale = bits.bits(1) != 0;
+    // Every endpoint takes at least one bit for its intensity
+    if count > data.len().saturating_mul(8) {
+        return Err(TranscodeError::InvalidData);
+    }
+
+    let mut endpoints = Vec::with_capacity(count);
+    let mut previous_color5 = [16; 3];
+    let mut previous_intensity = 0;
+    for _ in 0..count {
+        let intensity = ((bits.symbol(&intensity_delta_model)? + previous_intensity) & 7) as u8;
+        previous_intensity = intensity as u32;
+
+        let channels = if grayscale { 1 } else { 3 };
+        for previous in &mut previous_color5[..channels] {
+            let model = match *previous {
+                0..=9 => &color5_delta_models[0],
+                10..=21 => &color5_delta_models[1],
+                _ => &color5_delta_models[2],
+            };
+            *previous = ((*previous as u32 + bits.symbol(model)?) & 31) as u8;
+        }
+        if grayscale {
+            previous_color5 = [previous_color5[0]; 3];
+        }
+
+        endpoints.push(Endpoint {
+            color5: previous_color5,
+            intensity,
+        });
+    }
+    Ok(endpoints)
+}
+
+fn decode_selectors(data: &[u8], count: usize) -> Result<Vec<[u8; 4]>, TranscodeError> {
+    let mut bits = BitReader::new(data);
+    let global_codebook = bits.bits(1) != 0;
+    let hybrid_codebook = bits.bits(1) != 0;
+    if global_codebook || hybrid_codebook {
+        return Err(TranscodeError::InvalidData);
+    }
+    // Every selector takes at least one bit for each of its rows
+    if count > data.len().saturating_mul(2) {
+        return Err(TranscodeError::InvalidData);
+    }
+
+    let mut selectors = Vec::with_capacity(count);
+    if bits.bits(1) != 0 {
+        // Raw selectors
+        for _ in 0..count {
+            let mut rows = [0; 4];
+            for row in &mut rows {
+                *row = bits.bits(8) as u8;
+            }
+            selectors.push(rows);
+        }
+    } else {
+        // Rows XORed with the previous selector's rows
+        let delta_model = bits.huffman_table()?;
+        if count > 1 && delta_model.is_empty() {
+            return Err(TranscodeError::InvalidData);
+        }
+        let mut previous = [0; 4];
+        for i in 0..count {
+            for row in &mut previous {
+                *row = if i == 0 {
+                    bits.bits(8) as u8
+                } else {
+                    (bits.symbol(&delta_model)? as u8) ^ *row
+                };
+            }
+            selectors.push(previous);
+        }
+    }
+    Ok(selectors)
+}
+
+fn decode_tables(data: &[u8]) -> Result<SliceTables, TranscodeError> {
+    let mut bits = BitReader::new(data);
+    let tables = SliceTables {
+        endpoint_pred: bits.huffman_table()?,
+        delta_endpoint: bits.huffman_table()?,
+        selector: bits.huffman_table()?,
+        selector_history_rle: bits.huffman_table()?,
+        selector_history_size: bits.bits(13) as usize,
+    };
+    if tables.endpoint_pred.is_empty() || tables.selector.is_empty() || tables.selector_history_size == 0 {
+        return Err(TranscodeError::InvalidData);
+    }
+    Ok(tables)
+}
+
+/// `count` copies of `value`, failing instead of aborting if they can't be allocated.
+fn filled<T: Clone>(value: T, count: usize) -> Result<Vec<T>, TranscodeError> {
+    let mut values = Vec::new();
+    values
+        .try_reserve_exact(count)
+        .map_err(|_| TranscodeError::InvalidData)?;
+    values.resize(count, value);
+    Ok(values)
+}
+
+/// Least significant bit first reader. Reading past the end yields zeros.
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn bits(&mut self, count: u32) -> u32 {
+        let mut value = 0;
+        for i in 0..count {
+            let byte = self.data.get(self.position / 8).copied().unwrap_or(0);
+            value |= (((byte >> (self.position % 8)) & 1) as u32) << i;
+            self.position += 1;
+        }
+        value
+    }
+
+    /// Variable length code made of `chunk_bits` chunks, each followed by a continuation bit.
+    fn vlc(&mut self, chunk_bits: u32) -> u32 {
+        let mut value = 0;
+        let mut shift = 0;
+        while shift < 32 {
+            let chunk = self.bits(chunk_bits + 1);
+            value |= (chunk & ((1 << chunk_bits) - 1)) << shift;
+            shift += chunk_bits;
+            if chunk & (1 << chunk_bits) == 0 {
+                break;
+            }
+        }
+        value
+    }
+
+    fn symbol(&mut self, table: &HuffmanTable) -> Result<u32, TranscodeError> {
+        let mut code = 0;
+        let mut first = 0;
+        let mut index = 0;
+        for &count in &table.counts[1..] {
+            code |= self.bits(1);
+            if code - first < count as u32 {
+                return Ok(table.symbols[(index + code - first) as usize] as u32);
+            }
+            index += count as u32;
+            first = (first + count as u32) << 1;
+            code <<= 1;
+        }
+        Err(TranscodeError::InvalidData)
+    }
+
+    fn huffman_table(&mut self) -> Result<HuffmanTable, TranscodeError> {
+        let symbol_count = self.bits(14) as usize;
+        if symbol_count == 0 {
+            return Ok(HuffmanTable::new(&[]));
+        }
+
+        let code_length_code_count = self.bits(5) as usize;
+        if !(1..=CODE_LENGTH_CODE_ORDER.len()).contains(&code_length_code_count) {
+            return Err(TranscodeError::InvalidData);
+        }
+        let mut code_length_code_sizes = [0; CODE_LENGTH_CODE_ORDER.len()];
+        for &symbol in &CODE_LENGTH_CODE_ORDER[..code_length_code_count] {
+            code_length_code_sizes[symbol] = self.bits(3) as u8;
+        }
+        let code_length_table = HuffmanTable::new(&code_length_code_sizes);
+        if code_length_table.is_empty() {
+            return Err(TranscodeError::InvalidData);
+        }
+
+        let mut code_sizes = vec![0; symbol_count];
+        let mut i = 0;
+        while i < symbol_count {
+            match self.symbol(&code_length_table)? {
+                size @ 0..=16 => {
+                    code_sizes[i] = size as u8;
+                    i += 1;
+                }
+                // Zero runs
+                17 => i += self.bits(3) as usize + 3,
+                18 => i += self.bits(7) as usize + 11,
+                // Repeats of the previous size
+                code => {
+                    let count = if code == 19 {
+                        self.bits(2) as usize + 3
+                    } else {
+                        self.bits(7) as usize + 7
+                    };
+                    let previous = match i.checked_sub(1) {
+                        Some(previous) if code_sizes[previous] != 0 => code_sizes[previous],
+                        _ => return Err(TranscodeError::InvalidData),
+                    };
+                    if i + count > symbol_count {
+                        return Err(TranscodeError::InvalidData);
+                    }
+                    code_sizes[i..i + count].fill(previous);
+                    i += count;
+                }
+            }
+        }
+        if i != symbol_count {
+            return Err(TranscodeError::InvalidData);
+        }
+        Ok(HuffmanTable::new(&code_sizes))
+    }
+}
+
+/// Canonical Huffman code, decoded one bit at a time.
+#[derive(Clone, Debug)]
+struct HuffmanTable {
+    /// Number of codes of each length
+    counts: [u16; MAX_CODE_SIZE + 1],
+    /// Symbols ordered by code length, then by value
+    symbols: Vec<u16>,
+}
+
+impl HuffmanTable {
+    fn new(code_sizes: &[u8]) -> Self {
+        let mut counts = [0; MAX_CODE_SIZE + 1];
+        for &size in code_sizes {
+            counts[size as usize] += 1;
+        }
+        counts[0] = 0;
+
+        let mut symbols = Vec::with_capacity(code_sizes.len());
+        for size in 1..=MAX_CODE_SIZE as u8 {
+            symbols.extend((0..code_sizes.len() as u16).filter(|&symbol| code_sizes[symbol as usize] == size));
+        }
+        Self { counts, symbols }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+/// Approximate move-to-front list of recently used selectors.
+struct SelectorHistory {
+    values: Vec<usize>,
+    rover: usize,
+}
+
+impl SelectorHistory {
+    fn new(size: usize) -> Self {
+        Self {
+            values: vec![0; size],
+            rover: size / 2,
+        }
+    }
+
+    fn get(&self, index: usize) -> Option<usize> {
+        self.values.get(index).copied()
+    }
+
+    fn add(&mut self, value: usize) {
+        self.values[self.rover] = value;
+        self.rover += 1;
+        if self.rover == self.values.len() {
+            self.rover = self.values.len() / 2;
+        }
+    }
+
+    fn use_index(&mut self, index: usize) {
+        self.values.swap(index / 2, index);
+    }
+}
+
+/// Least significant bit first writer, the inverse of [`BitReader`].
+#[cfg(test)]
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    position: usize,
+}
+
+#[cfg(test)]
+impl BitWriter {
+    fn bits(&mut self, value: u32, count: u32) {
+        for i in 0..count {
+            if self.position.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            *self.bytes.last_mut().unwrap() |= (((value >> i) & 1) as u8) << (self.position % 8);
+            self.position += 1;
+        }
+    }
+
+    /// Write a table where every symbol has a 5-bit code, so symbol `n` is written as `n`.
+    fn flat_huffman_table(&mut self, symbol_count: u32) {
+        self.bits(symbol_count, 14);
+        self.bits(CODE_LENGTH_CODE_ORDER.len() as u32, 5);
+        for _ in CODE_LENGTH_CODE_ORDER {
+            self.bits(5, 3);
+        }
+        for _ in 0..symbol_count {
+            self.code(5, 5);
+        }
+    }
+
+    /// Write a 5-bit code from a [`Self::flat_huffman_table`].
+    fn code(&mut self, code: u32, size: u32) {
+        for i in (0..size).rev() {
+            self.bits((code >> i) & 1, 1);
+        }
+    }
+
+    /// Write a table with the given code sizes, using zero runs and repeats, and return each symbol's canonical
+    /// code and size.
+    fn huffman_table(&mut self, code_sizes: &[u8]) -> Vec<(u32, u32)> {
+        self.bits(code_sizes.len() as u32, 14);
+        self.bits(CODE_LENGTH_CODE_ORDER.len() as u32, 5);
+        for _ in CODE_LENGTH_CODE_ORDER {
+            self.bits(5, 3);
+        }
+        let mut i = 0;
+        while i < code_sizes.len() {
+            let size = code_sizes[i];
+            let run = code_sizes[i..].iter().take_while(|&&next| next == size).count();
+            let previous = i.checked_sub(1).map(|previous| code_sizes[previous]);
+            let run = match (size, run) {
+                (0, 11..) => {
+                    let run = run.min(138);
+                    self.code(18, 5);
+                    self.bits(run as u32 - 11, 7);
+                    run
+                }
+                (0, 3..) => {
+                    let run = run.min(10);
+                    self.code(17, 5);
+                    self.bits(run as u32 - 3, 3);
+                    run
+                }
+                (_, 7..) if previous == Some(size) => {
+                    let run = run.min(134);
+                    self.code(20, 5);
+                    self.bits(run as u32 - 7, 7);
+                    run
+                }
+                (_, 3..) if previous == Some(size) => {
+                    let run = run.min(6);
+                    self.code(19, 5);
+                    self.bits(run as u32 - 3, 2);
+                    run
+                }
+                _ => {
+                    self.code(size as u32, 5);
+                    1
+                }
+            };
+            i += run;
+        }
+
+        let mut codes = vec![(0, 0); code_sizes.len()];
+        let mut first = 0;
+        for size in 1..=MAX_CODE_SIZE as u8 {
+            let mut code = first;
+            for (symbol, _) in code_sizes.iter().enumerate().filter(|&(_, &s)| s == size) {
+                codes[symbol] = (code, size as u32);
+                code += 1;
+            }
+            first = code << 1;
+        }
+        codes
+    }
+
+    fn symbol(&mut self, table: &[(u32, u32)], symbol: usize) {
+        let (code, size) = table[symbol];
+        self.code(code, size);
+    }
+}
+
+#[test]
+fn test_transcode_single_block() {
+    use core::convert::TryInto;
+
+    // Endpoint: color5 (20, 10, 5) and intensity 1
+    let mut endpoints = BitWriter::default();
+    for _ in 0..4 {
+        endpoints.flat_huffman_table(32);
+    }
+    endpoints.bits(0, 1);
+    endpoints.code(1, 5);
+    endpoints.code(4, 5);
+    endpoints.code(26, 5);
+    endpoints.code(21, 5);
+
+    // Selector: each row counts 0, 1, 2, 3
+    let mut selectors = BitWriter::default();
+    selectors.bits(0b100, 3);
+    for _ in 0..4 {
+        selectors.bits(0b11100100, 8);
+    }
+
+    let mut tables = BitWriter::default();
+    tables.flat_huffman_table(257);
+    tables.flat_huffman_table(1);
+    tables.flat_huffman_table(3);
+    tables.flat_huffman_table(64);
+    tables.bits(1, 13);
+
+    // Explicit endpoint delta and the first selector
+    let mut slice = BitWriter::default();
+    slice.code(3, 5);
+    slice.code(0, 5);
+    slice.code(0, 5);
+
+    let transcoder = Etc1sTranscoder {
+        endpoints: decode_endpoints(&endpoints.bytes, 1).unwrap(),
+        selectors: decode_selectors(&selectors.bytes, 1).unwrap(),
+        tables: decode_tables(&tables.bytes).unwrap(),
+    };
+    let description = BasisLZImageDescription {
+        image_flags: BasisLZImageFlags::empty(),
+        rgb_slice_byte_offset: 0,
+        rgb_slice_byte_length: slice.bytes.len() as u32,
+        alpha_slice_byte_offset: 0,
+        alpha_slice_byte_length: 0,
+    };
+    let transcode = |target| {
+        transcoder
+            .transcode_image(&description, &slice.bytes, 4, 4, target)
+            .unwrap()
+    };
+
+    let base = [165, 82, 41];
+    let rgba = transcode(TranscodeTarget::Rgba8);
+    for (i, pixel) in rgba.chunks_exact(4).enumerate() {
+        let modifier = INTENSITY_TABLES[1][i % 4];
+        let expected = base.map(|value: i32| (value + modifier) as u8);
+        assert_eq!(pixel, [expected[0], expected[1], expected[2], 255]);
+    }
+
+    // Partial blocks are clipped at the image edges
+    let clipped = transcoder
+        .transcode_image(&description, &slice.bytes, 3, 2, TranscodeTarget::Rgba8)
+        .unwrap();
+    assert_eq!(clipped.len(), 3 * 2 * 4);
+    assert_eq!(clipped[..12], rgba[..12]);
+    assert_eq!(clipped[12..], rgba[16..28]);
+
+    let etc2 = transcode(TranscodeTarget::Etc2Rgba);
+    assert_eq!(etc2.len(), 16);
+    assert_eq!(&etc2[8..12], &[20 << 3, 10 << 3, 5 << 3, 0b00100111]);
+    // Columns map to ETC1 indices 3, 2, 0 and 1
+    assert_eq!(&etc2[12..16], &[0x00, 0xFF, 0xF0, 0x0F]);
+    // The ETC1 block decodes to the RGBA8 output under the decoding rules of the specification
+    for (i, pixel) in rgba.chunks_exact(4).enumerate() {
+        assert_eq!(
+            pixel[..3],
+            decode_etc1_differential(etc2[8..16].try_into().unwrap(), i % 4, i / 4)
+        );
+    }
+
+    for target in [TranscodeTarget::Bc1, TranscodeTarget::Bc3, TranscodeTarget::Bc7] {
+        assert!(!transcode(target).is_empty());
+    }
+
+    // Dimensions far beyond what the slice can describe are rejected before allocating
+    for (width, height) in [(u32::MAX, u32::MAX), (1 << 21, 1 << 21)] {
+        assert!(matches!(
+            transcoder.transcode_image(&description, &slice.bytes, width, height, TranscodeTarget::Rgba8),
+            Err(TranscodeError::InvalidData)
+        ));
+    }
+    assert!(matches!(
+        decode_endpoints(&endpoints.bytes, u32::MAX as usize),
+        Err(TranscodeError::InvalidData)
+    ));
+    assert!(matches!(
+        decode_selectors(&selectors.bytes, u32::MAX as usize),
+        Err(TranscodeError::InvalidData)
+    ));
+}
+
+/// ETC1 modifier tables of the Khronos Data Format Specification, as the magnitudes for pixel indices 0 and 1.
+#[cfg(test)]
+const ETC1_MODIFIERS: [[i32; 2]; 8] = [
+    [2, 8],
+    [5, 17],
+    [9, 29],
+    [13, 42],
+    [18, 60],
+    [24, 80],
+    [33, 106],
+    [47, 183],
+];
+
+/// Pixel at `x`, `y` of a differential mode ETC1 block, following the Khronos Data Format Specification
+/// independently of the transcoder's tables.
+#[cfg(test)]
+fn decode_etc1_differential(block: [u8; 8], x: usize, y: usize) -> [u8; 3] {
+    assert!(block[3] & 0b10 != 0, "individual mode");
+
+    let flipped = block[3] & 1 != 0;
+    let second = if flipped { y >= 2 } else { x >= 2 };
+    let table = if second { block[3] >> 2 & 7 } else { block[3] >> 5 };
+    let bit = x * 4 + y;
+    let msb = (u16::from_be_bytes([block[4], block[5]]) >> bit) & 1;
+    let lsb = (u16::from_be_bytes([block[6], block[7]]) >> bit) & 1;
+    let [a, b] = ETC1_MODIFIERS[table as usize];
+    let modifier = [a, b, -a, -b][(msb << 1 | lsb) as usize];
+
+    [0, 1, 2].map(|channel| {
+        let base = block[channel] >> 3;
+        let delta = ((block[channel] & 7) << 5) as i8 >> 5;
+        let color5 = if second { (base as i8 + delta) as u8 & 31 } else { base };
+        let color = ((color5 << 3) | (color5 >> 2)) as i32;
+        (color + modifier).clamp(0, 255) as u8
+    })
+}
+
+#[test]
+fn test_transcode_etc1s_texture() {
+    use crate::{
+        test_util::{test_tex_dfd, writer_header},
+        BasisLZGlobalHeader, Level, Writer, WriterHeader,
+    };
+    use core::convert::TryInto;
+
+    // 5x3 blocks, with a partial last column and row
+    let (width, height) = (18, 11);
+    let endpoints: [([u8; 3], u8); 3] = [([4, 20, 28], 2), ([31, 0, 15], 7), ([10, 10, 10], 0)];
+    let selectors = [
+        [0b11100100; 4],
+        [0x00, 0x55, 0xAA, 0xFF],
+        [0x1B, 0x1B, 0xE4, 0xE4],
+        [0xFF, 0x00, 0x33, 0xCC],
+    ];
+    // The endpoint and selector of each block encoded below
+    let expected_endpoints = [0, 1, 1, 2, 2, 0, 0, 2, 2, 1, 0, 0, 2, 2, 1];
+    let expected_selectors = [0, 1, 2, 2, 1, 3, 2, 2, 2, 3, 2, 2, 2, 2, 0];
+
+    // Endpoints as deltas, with color models chosen by the previous value
+    let mut endpoint_bits = BitWriter::default();
+    let mut color5_delta = [6; 32];
+    color5_delta[0] = 1;
+    let color5_models = [(); 3].map(|_| endpoint_bits.huffman_table(&color5_delta));
+    let intensity_model = endpoint_bits.huffman_table(&[3; 8]);
+    endpoint_bits.bits(0, 1);
+    let (mut previous_color5, mut previous_intensity) = ([16u8; 3], 0u8);
+    for (color5, intensity) in endpoints {
+        endpoint_bits.symbol(
+            &intensity_model,
+            (intensity.wrapping_sub(previous_intensity) & 7) as usize,
+        );
+        previous_intensity = intensity;
+        for (previous, value) in previous_color5.iter_mut().zip(color5) {
+            let model = match *previous {
+                0..=9 => &color5_models[0],
+                10..=21 => &color5_models[1],
+                _ => &color5_models[2],
+            };
+            endpoint_bits.symbol(model, (value.wrapping_sub(*previous) & 31) as usize);
+            *previous = value;
+        }
+    }
+
+    // Selectors as rows XORed with the previous selector's rows
+    let mut selector_bits = BitWriter::default();
+    selector_bits.bits(0, 3);
+    let mut delta_sizes = [9; 256];
+    delta_sizes[0] = 1;
+    let delta_model = selector_bits.huffman_table(&delta_sizes);
+    for row in selectors[0] {
+        selector_bits.bits(row as u32, 8);
+    }
+    for (previous, selector) in selectors.iter().zip(&selectors[1..]) {
+        for (previous, row) in previous.iter().zip(selector) {
+            selector_bits.symbol(&delta_model, (previous ^ row) as usize);
+        }
+    }
+
+    // Selector symbols are the 4 selectors, 3 history entries and the run symbol
+    let mut table_bits = BitWriter::default();
+    let mut pred_sizes = [12; 257];
+    for symbol in [159, 60, 48, 0x55, 256] {
+        pred_sizes[symbol] = 3;
+    }
+    let pred_model = table_bits.huffman_table(&pred_sizes);
+    let delta_endpoint_model = table_bits.huffman_table(&[1, 2, 2]);
+    let selector_model = table_bits.huffman_table(&[2, 2, 3, 3, 4, 4, 4, 4]);
+    let mut rle_sizes = [8; 64];
+    (rle_sizes[0], rle_sizes[63]) = (1, 2);
+    let rle_model = table_bits.huffman_table(&rle_sizes);
+    table_bits.bits(3, 13);
+
+    let mut slice = BitWriter::default();
+    let pred = |slice: &mut BitWriter, symbol| slice.symbol(&pred_model, symbol);
+    let delta = |slice: &mut BitWriter, delta| slice.symbol(&delta_endpoint_model, delta);
+    let selector = |slice: &mut BitWriter, symbol| slice.symbol(&selector_model, symbol);
+    // Row 0: explicit, explicit and left, left and explicit. Row 1 uses the lower bits of the same groups.
+    pred(&mut slice, 3 | 3 << 2 | 1 << 4 | 2 << 6);
+    delta(&mut slice, 0);
+    selector(&mut slice, 0);
+    delta(&mut slice, 1);
+    selector(&mut slice, 1);
+    pred(&mut slice, 3 << 2 | 3 << 4);
+    selector(&mut slice, 2);
+    delta(&mut slice, 1);
+    // History entry 1 and 2
+    selector(&mut slice, 5);
+    pred(&mut slice, 3 << 4);
+    selector(&mut slice, 6);
+    // Row 1: upper, upper left, explicit, left and explicit past the last endpoint
+    selector(&mut slice, 3);
+    // Run of 3 of history entry 0
+    selector(&mut slice, 7);
+    slice.symbol(&rle_model, 0);
+    delta(&mut slice, 2);
+    delta(&mut slice, 2);
+    selector(&mut slice, 6);
+    // Row 2: upper for every block, repeating the first group's symbol for the other two
+    pred(&mut slice, 0x55);
+    selector(&mut slice, 7);
+    slice.symbol(&rle_model, 63);
+    slice.bits(1, 8);
+    pred(&mut slice, 256);
+    slice.bits(0, 5);
+    selector(&mut slice, 0);
+
+    // The slice doubles as the alpha slice
+    let descriptions = [BasisLZImageDescription {
+        image_flags: BasisLZImageFlags::empty(),
+        rgb_slice_byte_offset: 0,
+        rgb_slice_byte_length: slice.bytes.len() as u32,
+        alpha_slice_byte_offset: 0,
+        alpha_slice_byte_length: slice.bytes.len() as u32,
+    }];
+    let global_header = BasisLZGlobalHeader {
+        endpoint_count: endpoints.len() as u16,
+        selector_count: selectors.len() as u16,
+        endpoints_byte_length: endpoint_bits.bytes.len() as u32,
+        selectors_byte_length: selector_bits.bytes.len() as u32,
+        tables_byte_length: table_bits.bytes.len() as u32,
+        extended_byte_length: 0,
+    };
+    let mut sgd = global_header.as_bytes().to_vec();
+    for description in &descriptions {
+        sgd.extend_from_slice(&description.as_bytes());
+    }
+    for section in [&endpoint_bits, &selector_bits, &table_bits] {
+        sgd.extend_from_slice(&section.bytes);
+    }
+
+    let mut writer = Writer::new(WriterHeader {
+        pixel_height: height,
+        supercompression_scheme: Some(crate::SupercompressionScheme::BasisLZ),
+        ..writer_header(None, width)
+    });
+    writer
+        .data_format_descriptors(test_tex_dfd())
+        .supercompression_global_data(&sgd)
+        .level(Level {
+            data: &slice.bytes,
+            uncompressed_byte_length: 0,
+        });
+    let bytes = writer.write().unwrap();
+    let reader = Reader::new(&bytes[..]).unwrap();
+    let transcode = |target| reader.transcode_etc1s(target).unwrap().remove(0);
+
+    // Reference pixels from the specification's modifier tables, with alpha from the green channel
+    let block_pixel = |block: usize, x: usize, y: usize| {
+        let (color5, intensity) = endpoints[expected_endpoints[block]];
+        let selector = (selectors[expected_selectors[block]][y] >> (2 * x)) & 3;
+        let [a, b] = ETC1_MODIFIERS[intensity as usize];
+        let modifier = [-b, -a, a, b][selector as usize];
+        let [r, g, b] = color5.map(|value: u8| ((((value << 3) | (value >> 2)) as i32) + modifier).clamp(0, 255) as u8);
+        [r, g, b, g]
+    };
+    let rgba = transcode(TranscodeTarget::Rgba8);
+    assert_eq!(rgba.len(), (width * height * 4) as usize);
+    for (i, pixel) in rgba.chunks_exact(4).enumerate() {
+        let (x, y) = (i % width as usize, i / width as usize);
+        assert_eq!(
+            pixel,
+            block_pixel((y / 4) * 5 + x / 4, x % 4, y % 4),
+            "pixel {} {}",
+            x,
+            y
+        );
+    }
+
+    // Block formats pick the nearest palette entry to each reference pixel. Blocks without clamped colors lie on
+    // a line, which every encoder's endpoints follow closely.
+    let clamped = |block: usize| endpoints[expected_endpoints[block]].1 == 7;
+    let check = |target, block_length: usize, decode: &dyn Fn(&[u8]) -> Vec<Palette>| {
+        let blocks = transcode(target);
+        assert_eq!(blocks.len(), 15 * block_length);
+        for (block, data) in blocks.chunks_exact(block_length).enumerate() {
+            for (palette, indices, channels) in decode(data) {
+                for (i, &index) in indices.iter().enumerate() {
+                    let expected = &block_pixel(block, i % 4, i / 4)[channels.clone()];
+                    let distance = |color: &[u8]| {
+                        color
+                            .iter()
+                            .zip(expected)
+                            .map(|(&a, &b)| (a as i32 - b as i32).pow(2))
+                            .sum::<i32>()
+                    };
+                    let decoded = &palette[index][..];
+                    let nearest = palette.iter().map(|color| distance(color)).min().unwrap();
+                    assert_eq!(distance(decoded), nearest, "{:?} block {} pixel {}", target, block, i);
+                    if !clamped(block) {
+                        assert!(
+                            decoded
+                                .iter()
+                                .zip(expected)
+                                .all(|(&a, &b)| (a as i32 - b as i32).abs() <= 4),
+                            "{:?} block {} pixel {}",
+                            target,
+                            block,
+                            i
+                        );
+                    }
+                }
+            }
+        }
+    };
+    check(TranscodeTarget::Bc1, 8, &|data| vec![decode_bc1(data)]);
+    check(TranscodeTarget::Bc3, 16, &|data| {
+        vec![decode_bc4(&data[..8]), decode_bc1(&data[8..])]
+    });
+    check(TranscodeTarget::Bc7, 16, &|data| vec![decode_bc7_mode6(data)]);
+    check(TranscodeTarget::Etc2Rgba, 16, &|data| vec![decode_eac(&data[..8])]);
+    // The ETC1 half is exact
+    for (block, data) in transcode(TranscodeTarget::Etc2Rgba).chunks_exact(16).enumerate() {
+        for i in 0..16 {
+            let rgb = decode_etc1_differential(data[8..].try_into().unwrap(), i % 4, i / 4);
+            assert_eq!(rgb[..], block_pixel(block, i % 4, i / 4)[..3]);
+        }
+    }
+}
+
+/// Palette of a decoded block, each pixel's palette index and the RGBA channels the palette covers.
+#[cfg(test)]
+type Palette = (Vec<Vec<u8>>, [usize; 16], core::ops::Range<usize>);
+
+/// BC1 palette, following the Direct3D specification with the encoder's rounding.
+#[cfg(test)]
+fn decode_bc1(block: &[u8]) -> Palette {
+    let color = |bytes: [u8; 2]| {
+        let color = u16::from_le_bytes(bytes) as u32;
+        let (r, g, b) = (color >> 11, (color >> 5) & 63, color & 31);
+        ([r << 3 | r >> 2, g << 2 | g >> 4, b << 3 | b >> 2], color)
+    };
+    let ((a, color0), (b, color1)) = (color([block[0], block[1]]), color([block[2], block[3]]));
+    let palette: [[u32; 3]; 4] = if color0 > color1 {
+        [
+            a,
+            b,
+            [0, 1, 2].map(|c| (2 * a[c] + b[c] + 1) / 3),
+            [0, 1, 2].map(|c| (a[c] + 2 * b[c] + 1) / 3),
+        ]
+    } else {
+        [a, b, [0, 1, 2].map(|c| (a[c] + b[c]) / 2), [0; 3]]
+    };
+    let indices = u32::from_le_bytes([block[4], block[5], block[6], block[7]]);
+    (
+        palette
+            .iter()
+            .map(|color| color.iter().map(|&value| value as u8).collect())
+            .collect(),
+        core::array::from_fn(|i| (indices >> (2 * i) & 3) as usize),
+        0..3,
+    )
+}
+
+/// BC4 palette of the alpha channel, following the Direct3D specification with the encoder's rounding.
+#[cfg(test)]
+fn decode_bc4(block: &[u8]) -> Palette {
+    let (a, b) = (block[0] as u32, block[1] as u32);
+    let mut palette = [a, b, 0, 0, 0, 0, 0, 255];
+    if a > b {
+        for i in 1..7 {
+            palette[i + 1] = ((7 - i) as u32 * a + i as u32 * b + 3) / 7;
+        }
+    } else {
+        for i in 1..5 {
+            palette[i + 1] = ((5 - i) as u32 * a + i as u32 * b + 2) / 5;
+        }
+        palette[6] = 0;
+    }
+    let mut indices = [0; 8];
+    indices[..6].copy_from_slice(&block[2..8]);
+    let indices = u64::from_le_bytes(indices);
+    (
+        palette.iter().map(|&value| vec![value as u8]).collect(),
+        core::array::from_fn(|i| (indices >> (3 * i) & 7) as usize),
+        3..4,
+    )
+}
+
+/// BC7 mode 6 palette, following the Direct3D specification.
+#[cfg(test)]
+fn decode_bc7_mode6(block: &[u8]) -> Palette {
+    use core::convert::TryInto;
+
+    const WEIGHTS: [u32; 16] = [0, 4, 9, 13, 17, 21, 26, 30, 34, 38, 43, 47, 51, 55, 60, 64];
+    let bits = u128::from_le_bytes(block.try_into().unwrap());
+    let field = |offset: u32, length: u32| ((bits >> offset) & ((1 << length) - 1)) as u32;
+    assert_eq!(field(0, 7), 1 << 6, "mode 6");
+
+    let endpoint = |endpoint: u32| {
+        let p = field(63 + endpoint, 1);
+        [0, 1, 2, 3].map(|channel| field(7 + 14 * channel + 7 * endpoint, 7) << 1 | p)
+    };
+    let (a, b) = (endpoint(0), endpoint(1));
+    (
+        WEIGHTS
+            .iter()
+            .map(|&weight| {
+                (0..4)
+                    .map(|c| (((64 - weight) * a[c] + weight * b[c] + 32) >> 6) as u8)
+                    .collect()
+            })
+            .collect(),
+        core::array::from_fn(|i| {
+            if i == 0 {
+                field(65, 3) as usize
+            } else {
+                field(64 + 4 * i as u32, 4) as usize
+            }
+        }),
+        0..4,
+    )
+}
+
+/// EAC palette of the alpha channel, following the Khronos Data Format Specification.
+#[cfg(test)]
+fn decode_eac(block: &[u8]) -> Palette {
+    let (base, multiplier, table) = (block[0] as i32, (block[1] >> 4) as i32, (block[1] & 15) as usize);
+    let mut indices = [0; 8];
+    indices[2..].copy_from_slice(&block[2..8]);
+    let indices = u64::from_be_bytes(indices);
+    (
+        encode::EAC_MODIFIERS[table]
+            .iter()
+            .map(|&modifier| vec![(base + modifier * multiplier).clamp(0, 255) as u8])
+            .collect(),
+        // Pixels are stored column-major, starting from the most significant bit
+        core::array::from_fn(|i| (indices >> (45 - 3 * ((i % 4) * 4 + i / 4)) & 7) as usize),
+        3..4,
+    )
+}
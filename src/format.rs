@@ -0,0 +1,454 @@
+use crate::Format;
+
+bitflags::bitflags! {
+    /// Aspects of an image in a given format
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct FormatAspects: u8 {
+        const COLOR     = (1 << 0);
+        const DEPTH     = (1 << 1);
+        const STENCIL   = (1 << 2);
+    }
+}
+
+/// Numeric format of a format's components
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Suffix {
+    Unorm,
+    Snorm,
+    Uint,
+    Sint,
+    Sfloat,
+    Ufloat,
+    Srgb,
+}
+
+#[derive(Copy, Clone)]
+struct FormatInfo {
+    block_dimensions: [u32; 3],
+    block_byte_length: u32,
+    component_count: u32,
+    compressed: bool,
+    aspects: FormatAspects,
+    suffix: Option<Suffix>,
+}
+
+/// Metadata of known formats. Unknown formats have 1x1x1 blocks of 0 bytes, no components, no aspects and no
+/// numeric format.
+///
+/// Combined depth/stencil formats report the numeric format of their depth component.
+impl Format {
+    /// Width, height and depth of a texel block in pixels
+    pub const fn texel_block_dimensions(self) -> [u32; 3] {
+        self.info().block_dimensions
+    }
+
+    /// Bytes per texel block, which is a single pixel for uncompressed formats
+    pub const fn block_byte_length(self) -> u32 {
+        self.info().block_byte_length
+    }
+
+    pub const fn component_count(self) -> u32 {
+        self.info().component_count
+    }
+
+    /// Whether the format is block compressed
+    pub const fn is_compressed(self) -> bool {
+        self.info().compressed
+    }
+
+    pub const fn aspects(self) -> FormatAspects {
+        self.info().aspects
+    }
+
+    pub const fn is_srgb(self) -> bool {
+        matches!(self.info().suffix, Some(Suffix::Srgb))
+    }
+
+    pub const fn is_signed(self) -> bool {
+        matches!(self.info().suffix, Some(Suffix::Snorm | Suffix::Sint | Suffix::Sfloat))
+    }
+
+    pub const fn is_integer(self) -> bool {
+        matches!(self.info().suffix, Some(Suffix::Uint | Suffix::Sint))
+    }
+
+    /// Whether components are normalized to [0, 1] or [-1, 1], including sRGB formats
+    pub const fn is_normalized(self) -> bool {
+        matches!(self.info().suffix, Some(Suffix::Unorm | Suffix::Snorm | Suffix::Srgb))
+    }
+
+    pub const fn is_float(self) -> bool {
+        matches!(self.info().suffix, Some(Suffix::Ufloat | Suffix::Sfloat))
+    }
+
+    /// sRGB counterpart of a UNORM format, or the format itself if it is already sRGB.
+    ///
+    /// Returns `None` for formats without an sRGB counterpart.
+    ///
+    /// ```rust
+    /// # use ktx2::Format;
+    /// assert_eq!(Format::BC7_UNORM_BLOCK.to_srgb(), Some(Format::BC7_SRGB_BLOCK));
+    /// assert_eq!(Format::R16_UNORM.to_srgb(), None);
+    /// ```
+    pub const fn to_srgb(self) -> Option<Format> {
+        match self.srgb_pair() {
+            Some([_, srgb]) => Some(srgb),
+            None => None,
+        }
+    }
+
+    /// UNORM counterpart of an sRGB format, or the format itself if it has an sRGB counterpart.
+    ///
+    /// Returns `None` for formats without an sRGB counterpart.
+    pub const fn to_linear(self) -> Option<Format> {
+        match self.srgb_pair() {
+            Some([linear, _]) => Some(linear),
+            None => None,
+        }
+    }
+
+    /// UNORM and sRGB formats that differ only in transfer function
+    const fn srgb_pair(self) -> Option<[Format; 2]> {
+        match self {
+            Format::R8_UNORM | Format::R8_SRGB => Some([Format::R8_UNORM, Format::R8_SRGB]),
+            Format::R8G8_UNORM | Format::R8G8_SRGB => Some([Format::R8G8_UNORM, Format::R8G8_SRGB]),
+            Format::R8G8B8_UNORM | Format::R8G8B8_SRGB => Some([Format::R8G8B8_UNORM, Format::R8G8B8_SRGB]),
+            Format::B8G8R8_UNORM | Format::B8G8R8_SRGB => Some([Format::B8G8R8_UNORM, Format::B8G8R8_SRGB]),
+            Format::R8G8B8A8_UNORM | Format::R8G8B8A8_SRGB => Some([Format::R8G8B8A8_UNORM, Format::R8G8B8A8_SRGB]),
+            Format::B8G8R8A8_UNORM | Format::B8G8R8A8_SRGB => Some([Format::B8G8R8A8_UNORM, Format::B8G8R8A8_SRGB]),
+            Format::BC1_RGB_UNORM_BLOCK | Format::BC1_RGB_SRGB_BLOCK => {
+                Some([Format::BC1_RGB_UNORM_BLOCK, Format::BC1_RGB_SRGB_BLOCK])
+            }
+            Format::BC1_RGBA_UNORM_BLOCK | Format::BC1_RGBA_SRGB_BLOCK => {
+                Some([Format::BC1_RGBA_UNORM_BLOCK, Format::BC1_RGBA_SRGB_BLOCK])
+            }
+            Format::BC2_UNORM_BLOCK | Format::BC2_SRGB_BLOCK => Some([Format::BC2_UNORM_BLOCK, Format::BC2_SRGB_BLOCK]),
+            Format::BC3_UNORM_BLOCK | Format::BC3_SRGB_BLOCK => Some([Format::BC3_UNORM_BLOCK, Format::BC3_SRGB_BLOCK]),
+            Format::BC7_UNORM_BLOCK | Format::BC7_SRGB_BLOCK => Some([Format::BC7_UNORM_BLOCK, Format::BC7_SRGB_BLOCK]),
+            Format::ETC2_R8G8B8_UNORM_BLOCK | Format::ETC2_R8G8B8_SRGB_BLOCK => {
+                Some([Format::ETC2_R8G8B8_UNORM_BLOCK, Format::ETC2_R8G8B8_SRGB_BLOCK])
+            }
+            Format::ETC2_R8G8B8A1_UNORM_BLOCK | Format::ETC2_R8G8B8A1_SRGB_BLOCK => {
+                Some([Format::ETC2_R8G8B8A1_UNORM_BLOCK, Format::ETC2_R8G8B8A1_SRGB_BLOCK])
+            }
+            Format::ETC2_R8G8B8A8_UNORM_BLOCK | Format::ETC2_R8G8B8A8_SRGB_BLOCK => {
+                Some([Format::ETC2_R8G8B8A8_UNORM_BLOCK, Format::ETC2_R8G8B8A8_SRGB_BLOCK])
+            }
+            Format::ASTC_4x4_UNORM_BLOCK | Format::ASTC_4x4_SRGB_BLOCK => {
+                Some([Format::ASTC_4x4_UNORM_BLOCK, Format::ASTC_4x4_SRGB_BLOCK])
+            }
+            Format::ASTC_5x4_UNORM_BLOCK | Format::ASTC_5x4_SRGB_BLOCK => {
+                Some([Format::ASTC_5x4_UNORM_BLOCK, Format::ASTC_5x4_SRGB_BLOCK])
+            }
+            Format::ASTC_5x5_UNORM_BLOCK | Format::ASTC_5x5_SRGB_BLOCK => {
+                Some([Format::ASTC_5x5_UNORM_BLOCK, Format::ASTC_5x5_SRGB_BLOCK])
+            }
+            Format::ASTC_6x5_UNORM_BLOCK | Format::ASTC_6x5_SRGB_BLOCK => {
+                Some([Format::ASTC_6x5_UNORM_BLOCK, Format::ASTC_6x5_SRGB_BLOCK])
+            }
+            Format::ASTC_6x6_UNORM_BLOCK | Format::ASTC_6x6_SRGB_BLOCK => {
+                Some([Format::ASTC_6x6_UNORM_BLOCK, Format::ASTC_6x6_SRGB_BLOCK])
+            }
+            Format::ASTC_8x5_UNORM_BLOCK | Format::ASTC_8x5_SRGB_BLOCK => {
+                Some([Format::ASTC_8x5_UNORM_BLOCK, Format::ASTC_8x5_SRGB_BLOCK])
+            }
+            Format::ASTC_8x6_UNORM_BLOCK | Format::ASTC_8x6_SRGB_BLOCK => {
+                Some([Format::ASTC_8x6_UNORM_BLOCK, Format::ASTC_8x6_SRGB_BLOCK])
+            }
+            Format::ASTC_8x8_UNORM_BLOCK | Format::ASTC_8x8_SRGB_BLOCK => {
+                Some([Format::ASTC_8x8_UNORM_BLOCK, Format::ASTC_8x8_SRGB_BLOCK])
+            }
+            Format::ASTC_10x5_UNORM_BLOCK | Format::ASTC_10x5_SRGB_BLOCK => {
+                Some([Format::ASTC_10x5_UNORM_BLOCK, Format::ASTC_10x5_SRGB_BLOCK])
+            }
+            Format::ASTC_10x6_UNORM_BLOCK | Format::ASTC_10x6_SRGB_BLOCK => {
+                Some([Format::ASTC_10x6_UNORM_BLOCK, Format::ASTC_10x6_SRGB_BLOCK])
+            }
+            Format::ASTC_10x8_UNORM_BLOCK | Format::ASTC_10x8_SRGB_BLOCK => {
+                Some([Format::ASTC_10x8_UNORM_BLOCK, Format::ASTC_10x8_SRGB_BLOCK])
+            }
+            Format::ASTC_10x10_UNORM_BLOCK | Format::ASTC_10x10_SRGB_BLOCK => {
+                Some([Format::ASTC_10x10_UNORM_BLOCK, Format::ASTC_10x10_SRGB_BLOCK])
+            }
+            Format::ASTC_12x10_UNORM_BLOCK | Format::ASTC_12x10_SRGB_BLOCK => {
+                Some([Format::ASTC_12x10_UNORM_BLOCK, Format::ASTC_12x10_SRGB_BLOCK])
+            }
+            Format::ASTC_12x12_UNORM_BLOCK | Format::ASTC_12x12_SRGB_BLOCK => {
+                Some([Format::ASTC_12x12_UNORM_BLOCK, Format::ASTC_12x12_SRGB_BLOCK])
+            }
+            _ => None,
+        }
+    }
+
+    const fn info(self) -> FormatInfo {
+        use Suffix::*;
+
+        match self {
+            Format::R4G4_UNORM_PACK8 => uncompressed(1, 2, Unorm),
+            Format::R4G4B4A4_UNORM_PACK16 => uncompressed(2, 4, Unorm),
+            Format::B4G4R4A4_UNORM_PACK16 => uncompressed(2, 4, Unorm),
+            Format::R5G6B5_UNORM_PACK16 => uncompressed(2, 3, Unorm),
+            Format::B5G6R5_UNORM_PACK16 => uncompressed(2, 3, Unorm),
+            Format::R5G5B5A1_UNORM_PACK16 => uncompressed(2, 4, Unorm),
+            Format::B5G5R5A1_UNORM_PACK16 => uncompressed(2, 4, Unorm),
+            Format::A1R5G5B5_UNORM_PACK16 => uncompressed(2, 4, Unorm),
+            Format::R8_UNORM => uncompressed(1, 1, Unorm),
+            Format::R8_SNORM => uncompressed(1, 1, Snorm),
+            Format::R8_UINT => uncompressed(1, 1, Uint),
+            Format::R8_SINT => uncompressed(1, 1, Sint),
+            Format::R8_SRGB => uncompressed(1, 1, Srgb),
+            Format::R8G8_UNORM => uncompressed(2, 2, Unorm),
+            Format::R8G8_SNORM => uncompressed(2, 2, Snorm),
+            Format::R8G8_UINT => uncompressed(2, 2, Uint),
+            Format::R8G8_SINT => uncompressed(2, 2, Sint),
+            Format::R8G8_SRGB => uncompressed(2, 2, Srgb),
+            Format::R8G8B8_UNORM => uncompressed(3, 3, Unorm),
+            Format::R8G8B8_SNORM => uncompressed(3, 3, Snorm),
+            Format::R8G8B8_UINT => uncompressed(3, 3, Uint),
+            Format::R8G8B8_SINT => uncompressed(3, 3, Sint),
+            Format::R8G8B8_SRGB => uncompressed(3, 3, Srgb),
+            Format::B8G8R8_UNORM => uncompressed(3, 3, Unorm),
+            Format::B8G8R8_SNORM => uncompressed(3, 3, Snorm),
+            Format::B8G8R8_UINT => uncompressed(3, 3, Uint),
+            Format::B8G8R8_SINT => uncompressed(3, 3, Sint),
+            Format::B8G8R8_SRGB => uncompressed(3, 3, Srgb),
+            Format::R8G8B8A8_UNORM => uncompressed(4, 4, Unorm),
+            Format::R8G8B8A8_SNORM => uncompressed(4, 4, Snorm),
+            Format::R8G8B8A8_UINT => uncompressed(4, 4, Uint),
+            Format::R8G8B8A8_SINT => uncompressed(4, 4, Sint),
+            Format::R8G8B8A8_SRGB => uncompressed(4, 4, Srgb),
+            Format::B8G8R8A8_UNORM => uncompressed(4, 4, Unorm),
+            Format::B8G8R8A8_SNORM => uncompressed(4, 4, Snorm),
+            Format::B8G8R8A8_UINT => uncompressed(4, 4, Uint),
+            Format::B8G8R8A8_SINT => uncompressed(4, 4, Sint),
+            Format::B8G8R8A8_SRGB => uncompressed(4, 4, Srgb),
+            Format::A2R10G10B10_UNORM_PACK32 => uncompressed(4, 4, Unorm),
+            Format::A2R10G10B10_SNORM_PACK32 => uncompressed(4, 4, Snorm),
+            Format::A2R10G10B10_UINT_PACK32 => uncompressed(4, 4, Uint),
+            Format::A2R10G10B10_SINT_PACK32 => uncompressed(4, 4, Sint),
+            Format::A2B10G10R10_UNORM_PACK32 => uncompressed(4, 4, Unorm),
+            Format::A2B10G10R10_SNORM_PACK32 => uncompressed(4, 4, Snorm),
+            Format::A2B10G10R10_UINT_PACK32 => uncompressed(4, 4, Uint),
+            Format::A2B10G10R10_SINT_PACK32 => uncompressed(4, 4, Sint),
+            Format::R16_UNORM => uncompressed(2, 1, Unorm),
+            Format::R16_SNORM => uncompressed(2, 1, Snorm),
+            Format::R16_UINT => uncompressed(2, 1, Uint),
+            Format::R16_SINT => uncompressed(2, 1, Sint),
+            Format::R16_SFLOAT => uncompressed(2, 1, Sfloat),
+            Format::R16G16_UNORM => uncompressed(4, 2, Unorm),
+            Format::R16G16_SNORM => uncompressed(4, 2, Snorm),
+            Format::R16G16_UINT => uncompressed(4, 2, Uint),
+            Format::R16G16_SINT => uncompressed(4, 2, Sint),
+            Format::R16G16_SFLOAT => uncompressed(4, 2, Sfloat),
+            Format::R16G16B16_UNORM => uncompressed(6, 3, Unorm),
+            Format::R16G16B16_SNORM => uncompressed(6, 3, Snorm),
+            Format::R16G16B16_UINT => uncompressed(6, 3, Uint),
+            Format::R16G16B16_SINT => uncompressed(6, 3, Sint),
+            Format::R16G16B16_SFLOAT => uncompressed(6, 3, Sfloat),
+            Format::R16G16B16A16_UNORM => uncompressed(8, 4, Unorm),
+            Format::R16G16B16A16_SNORM => uncompressed(8, 4, Snorm),
+            Format::R16G16B16A16_UINT => uncompressed(8, 4, Uint),
+            Format::R16G16B16A16_SINT => uncompressed(8, 4, Sint),
+            Format::R16G16B16A16_SFLOAT => uncompressed(8, 4, Sfloat),
+            Format::R32_UINT => uncompressed(4, 1, Uint),
+            Format::R32_SINT => uncompressed(4, 1, Sint),
+            Format::R32_SFLOAT => uncompressed(4, 1, Sfloat),
+            Format::R32G32_UINT => uncompressed(8, 2, Uint),
+            Format::R32G32_SINT => uncompressed(8, 2, Sint),
+            Format::R32G32_SFLOAT => uncompressed(8, 2, Sfloat),
+            Format::R32G32B32_UINT => uncompressed(12, 3, Uint),
+            Format::R32G32B32_SINT => uncompressed(12, 3, Sint),
+            Format::R32G32B32_SFLOAT => uncompressed(12, 3, Sfloat),
+            Format::R32G32B32A32_UINT => uncompressed(16, 4, Uint),
+            Format::R32G32B32A32_SINT => uncompressed(16, 4, Sint),
+            Format::R32G32B32A32_SFLOAT => uncompressed(16, 4, Sfloat),
+            Format::R64_UINT => uncompressed(8, 1, Uint),
+            Format::R64_SINT => uncompressed(8, 1, Sint),
+            Format::R64_SFLOAT => uncompressed(8, 1, Sfloat),
+            Format::R64G64_UINT => uncompressed(16, 2, Uint),
+            Format::R64G64_SINT => uncompressed(16, 2, Sint),
+            Format::R64G64_SFLOAT => uncompressed(16, 2, Sfloat),
+            Format::R64G64B64_UINT => uncompressed(24, 3, Uint),
+            Format::R64G64B64_SINT => uncompressed(24, 3, Sint),
+            Format::R64G64B64_SFLOAT => uncompressed(24, 3, Sfloat),
+            Format::R64G64B64A64_UINT => uncompressed(32, 4, Uint),
+            Format::R64G64B64A64_SINT => uncompressed(32, 4, Sint),
+            Format::R64G64B64A64_SFLOAT => uncompressed(32, 4, Sfloat),
+            Format::B10G11R11_UFLOAT_PACK32 => uncompressed(4, 3, Ufloat),
+            Format::E5B9G9R9_UFLOAT_PACK32 => uncompressed(4, 3, Ufloat),
+            Format::D16_UNORM => depth_stencil(2, FormatAspects::DEPTH, Unorm),
+            Format::X8_D24_UNORM_PACK32 => depth_stencil(4, FormatAspects::DEPTH, Unorm),
+            Format::D32_SFLOAT => depth_stencil(4, FormatAspects::DEPTH, Sfloat),
+            Format::S8_UINT => depth_stencil(1, FormatAspects::STENCIL, Uint),
+            Format::D16_UNORM_S8_UINT => depth_stencil(4, FormatAspects::DEPTH.union(FormatAspects::STENCIL), Unorm),
+            Format::D24_UNORM_S8_UINT => depth_stencil(4, FormatAspects::DEPTH.union(FormatAspects::STENCIL), Unorm),
+            Format::D32_SFLOAT_S8_UINT => depth_stencil(8, FormatAspects::DEPTH.union(FormatAspects::STENCIL), Sfloat),
+            Format::BC1_RGB_UNORM_BLOCK => compressed(4, 4, 8, 3, Unorm),
+            Format::BC1_RGB_SRGB_BLOCK => compressed(4, 4, 8, 3, Srgb),
+            Format::BC1_RGBA_UNORM_BLOCK => compressed(4, 4, 8, 4, Unorm),
+            Format::BC1_RGBA_SRGB_BLOCK => compressed(4, 4, 8, 4, Srgb),
+            Format::BC2_UNORM_BLOCK => compressed(4, 4, 16, 4, Unorm),
+            Format::BC2_SRGB_BLOCK => compressed(4, 4, 16, 4, Srgb),
+            Format::BC3_UNORM_BLOCK => compressed(4, 4, 16, 4, Unorm),
+            Format::BC3_SRGB_BLOCK => compressed(4, 4, 16, 4, Srgb),
+            Format::BC4_UNORM_BLOCK => compressed(4, 4, 8, 1, Unorm),
+            Format::BC4_SNORM_BLOCK => compressed(4, 4, 8, 1, Snorm),
+            Format::BC5_UNORM_BLOCK => compressed(4, 4, 16, 2, Unorm),
+            Format::BC5_SNORM_BLOCK => compressed(4, 4, 16, 2, Snorm),
+            Format::BC6H_UFLOAT_BLOCK => compressed(4, 4, 16, 3, Ufloat),
+            Format::BC6H_SFLOAT_BLOCK => compressed(4, 4, 16, 3, Sfloat),
+            Format::BC7_UNORM_BLOCK => compressed(4, 4, 16, 4, Unorm),
+            Format::BC7_SRGB_BLOCK => compressed(4, 4, 16, 4, Srgb),
+            Format::ETC2_R8G8B8_UNORM_BLOCK => compressed(4, 4, 8, 3, Unorm),
+            Format::ETC2_R8G8B8_SRGB_BLOCK => compressed(4, 4, 8, 3, Srgb),
+            Format::ETC2_R8G8B8A1_UNORM_BLOCK => compressed(4, 4, 8, 4, Unorm),
+            Format::ETC2_R8G8B8A1_SRGB_BLOCK => compressed(4, 4, 8, 4, Srgb),
+            Format::ETC2_R8G8B8A8_UNORM_BLOCK => compressed(4, 4, 16, 4, Unorm),
+            Format::ETC2_R8G8B8A8_SRGB_BLOCK => compressed(4, 4, 16, 4, Srgb),
+            Format::EAC_R11_UNORM_BLOCK => compressed(4, 4, 8, 1, Unorm),
+            Format::EAC_R11_SNORM_BLOCK => compressed(4, 4, 8, 1, Snorm),
+            Format::EAC_R11G11_UNORM_BLOCK => compressed(4, 4, 16, 2, Unorm),
+            Format::EAC_R11G11_SNORM_BLOCK => compressed(4, 4, 16, 2, Snorm),
+            Format::ASTC_4x4_UNORM_BLOCK => compressed(4, 4, 16, 4, Unorm),
+            Format::ASTC_4x4_SRGB_BLOCK => compressed(4, 4, 16, 4, Srgb),
+            Format::ASTC_4x4_SFLOAT_BLOCK => compressed(4, 4, 16, 4, Sfloat),
+            Format::ASTC_5x4_UNORM_BLOCK => compressed(5, 4, 16, 4, Unorm),
+            Format::ASTC_5x4_SRGB_BLOCK => compressed(5, 4, 16, 4, Srgb),
+            Format::ASTC_5x4_SFLOAT_BLOCK => compressed(5, 4, 16, 4, Sfloat),
+            Format::ASTC_5x5_UNORM_BLOCK => compressed(5, 5, 16, 4, Unorm),
+            Format::ASTC_5x5_SRGB_BLOCK => compressed(5, 5, 16, 4, Srgb),
+            Format::ASTC_5x5_SFLOAT_BLOCK => compressed(5, 5, 16, 4, Sfloat),
+            Format::ASTC_6x5_UNORM_BLOCK => compressed(6, 5, 16, 4, Unorm),
+            Format::ASTC_6x5_SRGB_BLOCK => compressed(6, 5, 16, 4, Srgb),
+            Format::ASTC_6x5_SFLOAT_BLOCK => compressed(6, 5, 16, 4, Sfloat),
+            Format::ASTC_6x6_UNORM_BLOCK => compressed(6, 6, 16, 4, Unorm),
+            Format::ASTC_6x6_SRGB_BLOCK => compressed(6, 6, 16, 4, Srgb),
+            Format::ASTC_6x6_SFLOAT_BLOCK => compressed(6, 6, 16, 4, Sfloat),
+            Format::ASTC_8x5_UNORM_BLOCK => compressed(8, 5, 16, 4, Unorm),
+            Format::ASTC_8x5_SRGB_BLOCK => compressed(8, 5, 16, 4, Srgb),
+            Format::ASTC_8x5_SFLOAT_BLOCK => compressed(8, 5, 16, 4, Sfloat),
+            Format::ASTC_8x6_UNORM_BLOCK => compressed(8, 6, 16, 4, Unorm),
+            Format::ASTC_8x6_SRGB_BLOCK => compressed(8, 6, 16, 4, Srgb),
+            Format::ASTC_8x6_SFLOAT_BLOCK => compressed(8, 6, 16, 4, Sfloat),
+            Format::ASTC_8x8_UNORM_BLOCK => compressed(8, 8, 16, 4, Unorm),
+            Format::ASTC_8x8_SRGB_BLOCK => compressed(8, 8, 16, 4, Srgb),
+            Format::ASTC_8x8_SFLOAT_BLOCK => compressed(8, 8, 16, 4, Sfloat),
+            Format::ASTC_10x5_UNORM_BLOCK => compressed(10, 5, 16, 4, Unorm),
+            Format::ASTC_10x5_SRGB_BLOCK => compressed(10, 5, 16, 4, Srgb),
+            Format::ASTC_10x5_SFLOAT_BLOCK => compressed(10, 5, 16, 4, Sfloat),
+            Format::ASTC_10x6_UNORM_BLOCK => compressed(10, 6, 16, 4, Unorm),
+            Format::ASTC_10x6_SRGB_BLOCK => compressed(10, 6, 16, 4, Srgb),
+            Format::ASTC_10x6_SFLOAT_BLOCK => compressed(10, 6, 16, 4, Sfloat),
+            Format::ASTC_10x8_UNORM_BLOCK => compressed(10, 8, 16, 4, Unorm),
+            Format::ASTC_10x8_SRGB_BLOCK => compressed(10, 8, 16, 4, Srgb),
+            Format::ASTC_10x8_SFLOAT_BLOCK => compressed(10, 8, 16, 4, Sfloat),
+            Format::ASTC_10x10_UNORM_BLOCK => compressed(10, 10, 16, 4, Unorm),
+            Format::ASTC_10x10_SRGB_BLOCK => compressed(10, 10, 16, 4, Srgb),
+            Format::ASTC_10x10_SFLOAT_BLOCK => compressed(10, 10, 16, 4, Sfloat),
+            Format::ASTC_12x10_UNORM_BLOCK => compressed(12, 10, 16, 4, Unorm),
+            Format::ASTC_12x10_SRGB_BLOCK => compressed(12, 10, 16, 4, Srgb),
+            Format::ASTC_12x10_SFLOAT_BLOCK => compressed(12, 10, 16, 4, Sfloat),
+            Format::ASTC_12x12_UNORM_BLOCK => compressed(12, 12, 16, 4, Unorm),
+            Format::ASTC_12x12_SRGB_BLOCK => compressed(12, 12, 16, 4, Srgb),
+            Format::ASTC_12x12_SFLOAT_BLOCK => compressed(12, 12, 16, 4, Sfloat),
+            _ => FormatInfo {
+                block_dimensions: [1; 3],
+                block_byte_length: 0,
+                component_count: 0,
+                compressed: false,
+                aspects: FormatAspects::empty(),
+                suffix: None,
+            },
+        }
+    }
+}
+
+const fn uncompressed(block_byte_length: u32, component_count: u32, suffix: Suffix) -> FormatInfo {
+    FormatInfo {
+        block_dimensions: [1; 3],
+        block_byte_length,
+        component_count,
+        compressed: false,
+        aspects: FormatAspects::COLOR,
+        suffix: Some(suffix),
+    }
+}
+
+const fn depth_stencil(block_byte_length: u32, aspects: FormatAspects, suffix: Suffix) -> FormatInfo {
+    FormatInfo {
+        block_dimensions: [1; 3],
+        block_byte_length,
+        component_count: aspects.bits().count_ones(),
+        compressed: false,
+        aspects,
+        suffix: Some(suffix),
+    }
+}
+
+const fn compressed(
+    width: u32,
+    height: u32,
+    block_byte_length: u32,
+    component_count: u32,
+    suffix: Suffix,
+) -> FormatInfo {
+    FormatInfo {
+        block_dimensions: [width, height, 1],
+        block_byte_length,
+        component_count,
+        compressed: true,
+        aspects: FormatAspects::COLOR,
+        suffix: Some(suffix),
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_format_metadata_matches_canonical_data_format_descriptors() {
+    use crate::{BasicDataFormatDescriptor, BasicDataFormatDescriptorBuilder};
+
+    for &format in Format::ALL {
+        let dfd = BasicDataFormatDescriptorBuilder::from_format(format)
+            .unwrap()
+            .build()
+            .unwrap();
+        let dfd = BasicDataFormatDescriptor::parse(&dfd[8..]).unwrap();
+        let [width, height, depth, _] = dfd.header.texel_block_dimensions;
+        assert_eq!(format.texel_block_dimensions(), [width, height, depth], "{:?}", format);
+        assert_eq!(format.block_byte_length(), dfd.header.bytes_planes[0], "{:?}", format);
+        assert_eq!(
+            format.is_srgb(),
+            dfd.header.transfer_function == Some(crate::TransferFunction::SRGB)
+        );
+    }
+
+    assert_eq!(Format::new(1).unwrap().block_byte_length(), 1);
+    assert_eq!(
+        Format::D24_UNORM_S8_UINT.aspects(),
+        FormatAspects::DEPTH | FormatAspects::STENCIL
+    );
+    assert!(Format::BC5_SNORM_BLOCK.is_signed() && Format::BC5_SNORM_BLOCK.is_normalized());
+    assert_eq!(Format::BC5_SNORM_BLOCK.component_count(), 2);
+    assert!(Format::R32G32_SINT.is_integer() && !Format::R32G32_SINT.is_float());
+    assert_eq!(Format::new(2000).unwrap().block_byte_length(), 0);
+}
+
+#[test]
+fn test_srgb_pairs() {
+    for &format in Format::ALL {
+        match (format.to_linear(), format.to_srgb()) {
+            (Some(linear), Some(srgb)) => {
+                assert!(!linear.is_srgb() && linear.is_normalized(), "{:?}", format);
+                assert!(srgb.is_srgb(), "{:?}", format);
+                assert_eq!(linear.to_srgb(), Some(srgb));
+                assert_eq!(srgb.to_linear(), Some(linear));
+                assert_eq!(linear.texel_block_dimensions(), srgb.texel_block_dimensions());
+            }
+            (None, None) => assert!(!format.is_srgb(), "{:?}", format),
+            pair => panic!("{:?} has a one-sided pair {:?}", format, pair),
+        }
+    }
+    assert_eq!(Format::R8G8B8A8_SRGB.to_linear(), Some(Format::R8G8B8A8_UNORM));
+    assert_eq!(Format::R8G8B8A8_SNORM.to_srgb(), None);
+}
@@ -0,0 +1,177 @@
+use crate::{
+    layout::LevelLayout, BasicDataFormatDescriptorHeader, DataFormatDescriptor, DataFormatDescriptorHeader, Header,
+    Reader,
+};
+use core::convert::TryFrom;
+
+/// A single image within a mip level.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Image<'a> {
+    pub level: u32,
+    pub layer: u32,
+    pub face: u32,
+    /// Index of the slice of texel blocks along the z axis. This is the pixel depth slice unless the format's
+    /// texel blocks are three-dimensional.
+    pub z_slice: u32,
+    pub data: &'a [u8],
+}
+
+/// How the images of a mip level are packed.
+#[derive(Copy, Clone)]
+pub(crate) struct ImageLayout {
+    pub(crate) layers: u32,
+    pub(crate) faces: u32,
+    pub(crate) z_slices: u32,
+    pub(crate) byte_length: usize,
+}
+
+impl ImageLayout {
+    /// Number of images in the level, or `None` if it doesn't fit in a `u64`
+    pub(crate) fn image_count(&self) -> Option<u64> {
+        (self.layers as u64)
+            .checked_mul(self.faces as u64)?
+            .checked_mul(self.z_slices as u64)
+    }
+
+    /// Index of an image within its level, or `None` if an index is out of range
+    pub(crate) fn image_index(&self, layer: u32, face: u32, z_slice: u32) -> Option<u64> {
+        if layer >= self.layers || face >= self.faces || z_slice >= self.z_slices {
+            return None;
+        }
+        (layer as u64 * self.faces as u64 + face as u64)
+            .checked_mul(self.z_slices as u64)?
+            .checked_add(z_slice as u64)
+    }
+}
+
+impl<Data: AsRef<[u8]>> Reader<Data> {
+    /// Data of a single image of an uncompressed level.
+    ///
+    /// `layer` and `z_slice` are 0 for textures without layers or depth. Returns `None` if an index is out of
+    /// range, the texture is supercompressed, or the level is too short for the layout given by the header and
+    /// data format descriptor.
+    pub fn image(&self, level: u32, layer: u32, face: u32, z_slice: u32) -> Option<&[u8]> {
+        let layout = image_layout(&self.header(), self.data_format_descriptors(), level)?;
+        let index = layout.image_index(layer, face, z_slice)?;
+        image_data(self.levels().nth(level as usize)?.data, index, layout.byte_length)
+    }
+
+    /// Iterator over every image of every uncompressed level, ordered by level, then layer, then face, then z slice.
+    ///
+    /// Supercompressed textures have no images.
+    pub fn images(&self) -> impl Iterator<Item = Image<'_>> + '_ {
+        (0..self.header().level_count.max(1)).flat_map(move |level| {
+            let layout = image_layout(&self.header(), self.data_format_descriptors(), level);
+            let data = self.levels().nth(level as usize).map(|level| level.data);
+            // Levels with more images than fit in a `u64` can't be stored, so they have none
+            let count = layout.and_then(|layout| layout.image_count()).unwrap_or(0);
+            (0..count).map_while(move |index| {
+                let layout = layout?;
+                // Both fit in a `u32` as `index` is less than the image count
+                let images_per_layer = layout.faces as u64 * layout.z_slices as u64;
+                Some(Image {
+                    level,
+                    layer: (index / images_per_layer) as u32,
+                    face: (index / layout.z_slices as u64 % layout.faces as u64) as u32,
+                    z_slice: (index % layout.z_slices as u64) as u32,
+                    data: image_data(data?, index, layout.byte_length)?,
+                })
+            })
+        })
+    }
+}
+
+/// Data of the image at `index` within a level's `data`, or `None` if the level is too short
+fn image_data(data: &[u8], index: u64, byte_length: usize) -> Option<&[u8]> {
+    let start = usize::try_from(index).ok()?.checked_mul(byte_length)?;
+    let end = start.checked_add(byte_length)?;
+    data.get(start..end)
+}
+
+/// Layout of an uncompressed level, from the header and the basic data format descriptor in `dfds`.
+pub(crate) fn image_layout<'a>(
+    header: &Header,
+    mut dfds: impl Iterator<Item = DataFormatDescriptor<'a>>,
+    level: u32,
+) -> Option<ImageLayout> {
+    if header.supercompression_scheme.is_some() || level >= header.level_count.max(1) {
+        return None;
+    }
+
+    let dfd = dfds.find(|dfd| {
+        dfd.header.vendor_id == DataFormatDescriptorHeader::BASIC.vendor_id
+            && dfd.header.descriptor_type == DataFormatDescriptorHeader::BASIC.descriptor_type
+    })?;
+    let dfd = BasicDataFormatDescriptorHeader::parse(dfd.data).ok()?;
+    let [block_width, block_height, block_depth, _] = dfd.texel_block_dimensions;
+    if dfd.bytes_planes[0] == 0 {
+        return None;
+    }
+    let layout = LevelLayout::new(
+        header,
+        level,
+        [block_width, block_height, block_depth],
+        dfd.bytes_planes[0],
+    )?;
+
+    Some(ImageLayout {
+        layers: header.layer_count.max(1),
+        faces: header.face_count,
+        z_slices: layout.blocks_z,
+        byte_length: usize::try_from(layout.slice_pitch).ok()?,
+    })
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_cube_array_images() {
+    use crate::{test_util::writer_header, BasicDataFormatDescriptorBuilder, Format, Level, Writer, WriterHeader};
+    use alloc::vec::Vec;
+
+    let dfd = BasicDataFormatDescriptorBuilder::from_format(Format::R8_UNORM)
+        .unwrap()
+        .build()
+        .unwrap();
+    // 2 layers of 6 faces, 4x4 then 2x2 pixels. Each image is filled with its own index.
+    let levels = [16, 4].map(|length| {
+        (0..12)
+            .flat_map(|image| [image; 16][..length].to_vec())
+            .collect::<Vec<u8>>()
+    });
+
+    let mut writer = Writer::new(WriterHeader {
+        layer_count: 2,
+        face_count: 6,
+        level_count: 2,
+        ..writer_header(Some(Format::R8_UNORM), 4)
+    });
+    writer.data_format_descriptors(&dfd);
+    for data in &levels {
+        writer.level(Level {
+            data,
+            uncompressed_byte_length: data.len() as u64,
+        });
+    }
+    let bytes = writer.write().unwrap();
+    let reader = Reader::new(&bytes[..]).unwrap();
+
+    assert_eq!(reader.image(0, 1, 2, 0), Some(&[8; 16][..]));
+    assert_eq!(reader.image(1, 0, 5, 0), Some(&[5; 4][..]));
+    assert_eq!(reader.image(1, 2, 0, 0), None);
+    assert_eq!(reader.image(0, 0, 6, 0), None);
+    assert_eq!(reader.image(0, 0, 0, 1), None);
+
+    let images = reader.images().collect::<Vec<_>>();
+    assert_eq!(images.len(), 24);
+    for image in images {
+        assert_eq!(image.data[0], (image.layer * 6 + image.face) as u8);
+        assert_eq!(image.data.len(), if image.level == 0 { 16 } else { 4 });
+    }
+
+    // Image counts that overflow 32 bits stop at the end of the level data
+    let mut bytes = bytes;
+    bytes[32..36].copy_from_slice(&u32::MAX.to_le_bytes());
+    let reader = Reader::new(&bytes[..]).unwrap();
+    assert_eq!(reader.image(0, u32::MAX - 1, 5, 0), None);
+    assert_eq!(reader.images().count(), 24);
+}
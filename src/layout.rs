@@ -0,0 +1,125 @@
+use crate::Header;
+
+/// Dimensions and byte sizes of a mip level.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LevelLayout {
+    /// Width in pixels, at least 1
+    pub width: u32,
+    /// Height in pixels, at least 1
+    pub height: u32,
+    /// Depth in pixels, at least 1
+    pub depth: u32,
+    pub blocks_x: u32,
+    pub blocks_y: u32,
+    pub blocks_z: u32,
+    /// Bytes per row of texel blocks
+    pub row_pitch: u64,
+    /// Bytes per slice of texel blocks along the z axis
+    pub slice_pitch: u64,
+    /// Bytes of a single face of a single layer
+    pub image_byte_length: u64,
+    /// Bytes of the whole level, including every layer and face
+    pub byte_length: u64,
+}
+
+impl LevelLayout {
+    /// Returns `None` if a size doesn't fit in a `u64`.
+    pub(crate) fn new(header: &Header, level: u32, block_dimensions: [u32; 3], block_byte_length: u32) -> Option<Self> {
+        let extent = |size: u32| size.checked_shr(level).unwrap_or(0).max(1);
+        let (width, height, depth) = (
+            extent(header.pixel_width),
+            extent(header.pixel_height),
+            extent(header.pixel_depth),
+        );
+        let (blocks_x, blocks_y, blocks_z) = (
+            width.div_ceil(block_dimensions[0]),
+            height.div_ceil(block_dimensions[1]),
+            depth.div_ceil(block_dimensions[2]),
+        );
+
+        let row_pitch = (blocks_x as u64).checked_mul(block_byte_length as u64)?;
+        let slice_pitch = row_pitch.checked_mul(blocks_y as u64)?;
+        let image_byte_length = slice_pitch.checked_mul(blocks_z as u64)?;
+        let images = header.layer_count.max(1) as u64 * header.face_count as u64;
+
+        Some(Self {
+            width,
+            height,
+            depth,
+            blocks_x,
+            blocks_y,
+            blocks_z,
+            row_pitch,
+            slice_pitch,
+            image_byte_length,
+            byte_length: image_byte_length.checked_mul(images)?,
+        })
+    }
+}
+
+impl Header {
+    /// Layout of an uncompressed `level`, derived from the header's format.
+    ///
+    /// Returns `None` if the level is out of range, the format is unknown or unspecified, as is the case for Basis
+    /// Universal textures, or the level's size doesn't fit in a `u64`.
+    ///
+    /// ```rust
+    /// # let file = include_bytes!("../data/test_tex.ktx2");
+    /// let reader = ktx2::Reader::new(file).unwrap();
+    /// let layout = reader.header().level_layout(1).unwrap();
+    /// assert_eq!((layout.width, layout.height), (512, 256));
+    /// assert_eq!(layout.row_pitch, 512 * 4);
+    /// assert_eq!(layout.byte_length, 512 * 256 * 4);
+    /// ```
+    pub fn level_layout(&self, level: u32) -> Option<LevelLayout> {
+        let format = self.format?;
+        if level >= self.level_count.max(1) || format.block_byte_length() == 0 {
+            return None;
+        }
+        LevelLayout::new(self, level, format.texel_block_dimensions(), format.block_byte_length())
+    }
+}
+
+#[test]
+fn test_level_layout() {
+    let header = Header {
+        format: Some(crate::Format::BC7_UNORM_BLOCK),
+        type_size: 1,
+        pixel_width: 10,
+        pixel_height: 6,
+        pixel_depth: 0,
+        layer_count: 3,
+        face_count: 1,
+        level_count: 4,
+        supercompression_scheme: None,
+        index: crate::Index {
+            dfd_byte_offset: 0,
+            dfd_byte_length: 0,
+            kvd_byte_offset: 0,
+            kvd_byte_length: 0,
+            sgd_byte_offset: 0,
+            sgd_byte_length: 0,
+        },
+    };
+
+    let layout = header.level_layout(0).unwrap();
+    assert_eq!((layout.blocks_x, layout.blocks_y, layout.blocks_z), (3, 2, 1));
+    assert_eq!(layout.row_pitch, 48);
+    assert_eq!(layout.slice_pitch, 96);
+    assert_eq!(layout.byte_length, 288);
+
+    let layout = header.level_layout(3).unwrap();
+    assert_eq!((layout.width, layout.height, layout.depth), (1, 1, 1));
+    assert_eq!(layout.byte_length, 48);
+
+    assert_eq!(header.level_layout(4), None);
+
+    let header = Header {
+        pixel_width: u32::MAX,
+        pixel_height: u32::MAX,
+        format: Some(crate::Format::R32G32B32A32_SFLOAT),
+        ..header
+    };
+    assert_eq!(header.level_layout(0), None);
+    assert_eq!(header.level_layout(3).unwrap().width, u32::MAX >> 3);
+}
@@ -1,11 +1,24 @@
 //! Parser for the [ktx2](https://github.khronos.org/KTX-Specification/) texture container format.
 //!
 //! ## Features
-//! - [x] Async reading
+//! - [x] Async reading of levels on demand (`futures-io` and `tokio` features)
 //! - [x] Parsing
+//! - [x] Probing the header, level index and metadata from the start of a file
+//! - [x] Progressive loading from range requests, smallest levels first
+//! - [x] Zero-copy reading of memory-mapped files (`mmap` feature)
+//! - [x] Streaming levels from `std::io::Read + Seek` sources (`std` feature)
 //! - [x] Validating
 //! - [x] [Data format description](https://github.khronos.org/KTX-Specification/#_data_format_descriptor)
-//! - [ ] [Key/value data](https://github.khronos.org/KTX-Specification/#_keyvalue_data)
+//! - [x] [Key/value data](https://github.khronos.org/KTX-Specification/#_keyvalue_data)
+//! - [x] Writing
+//! - [x] Zstandard supercompression (`zstd` feature)
+//! - [x] ZLIB supercompression (`zlib` feature)
+//! - [x] ETC1S transcoding to BC1, BC3, BC7, ETC2 and RGBA8 (`etc1s` feature)
+//! - [x] Conversions to and from `wgpu` texture formats (`wgpu` feature)
+//! - [x] Conversions to and from `ash` Vulkan formats, image create info and copy regions (`ash` feature)
+//! - [x] Conversions to and from `DXGI_FORMAT`
+//! - [x] Conversions to and from OpenGL internal format, format and type
+//! - [x] Conversions to and from `MTLPixelFormat`
 //
 //! ## Example
 //! ```rust
@@ -23,16 +36,83 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "ash")]
+mod ash;
+mod basis;
+#[cfg(feature = "alloc")]
+mod dfd;
+mod dxgi;
+#[cfg(feature = "etc1s")]
+mod encode;
 mod enums;
 mod error;
-
+#[cfg(feature = "etc1s")]
+mod etc1s;
+mod format;
+mod image;
+mod layout;
+mod metadata;
+mod metal;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod opengl;
+mod probe;
+#[cfg(feature = "alloc")]
+mod progressive;
+#[cfg(feature = "std")]
+#[macro_use]
+mod stream;
+#[cfg(feature = "futures-io")]
+mod stream_futures;
+#[cfg(feature = "tokio")]
+mod stream_tokio;
+#[cfg(any(feature = "zstd", feature = "zlib"))]
+mod supercompression;
+#[cfg(test)]
+mod test_util;
+#[cfg(feature = "alloc")]
+mod validate;
+#[cfg(feature = "wgpu")]
+mod wgpu;
+#[cfg(feature = "alloc")]
+mod writer;
+
+#[cfg(feature = "etc1s")]
+pub use crate::etc1s::{Etc1sTranscoder, TranscodeTarget};
+#[cfg(feature = "futures-io")]
+pub use crate::stream_futures::FuturesStreamReader;
+#[cfg(feature = "tokio")]
+pub use crate::stream_tokio::TokioStreamReader;
 pub use crate::{
-    enums::{ColorModel, ColorPrimaries, Format, SupercompressionScheme, TransferFunction},
-    error::ParseError,
+    basis::{BasisLZGlobalData, BasisLZGlobalHeader, BasisLZImageDescription, BasisLZImageFlags},
+    enums::{
+        ColorModel, ColorPrimaries, DxgiFormat, Format, MetalPixelFormat, SupercompressionScheme, TransferFunction,
+    },
+    error::{DecompressionError, FormatConversionError, FormatInferenceError, ParseError, TranscodeError, WriteError},
+    format::FormatAspects,
+    image::Image,
+    layout::LevelLayout,
+    metadata::{
+        AnimData, AstcDecodeMode, CubemapFaces, Orientation, OrientationX, OrientationY, OrientationZ, Swizzle,
+    },
+    opengl::GlFormat,
+    probe::Probe,
 };
+#[cfg(feature = "alloc")]
+pub use crate::{
+    dfd::BasicDataFormatDescriptorBuilder,
+    error::ChunkError,
+    progressive::{Chunk, ProgressiveReader, RangeRequest},
+    validate::{Severity, ValidationIssue},
+    writer::{Writer, WriterHeader},
+};
+#[cfg(feature = "std")]
+pub use crate::{error::StreamError, stream::StreamReader};
 
 use core::convert::TryInto;
 
@@ -51,80 +131,15 @@ impl<Data: AsRef<[u8]>> Reader<Data> {
         let header_data = input.as_ref()[0..Header::LENGTH].try_into().unwrap();
         let header = Header::from_bytes(header_data)?;
 
-        // Check DFD bounds
-        let dfd_start = header
-            .index
-            .dfd_byte_offset
-            .checked_add(4)
-            .ok_or(ParseError::UnexpectedEnd)?;
-        let dfd_end = header
-            .index
-            .dfd_byte_offset
-            .checked_add(header.index.dfd_byte_length)
-            .ok_or(ParseError::UnexpectedEnd)?;
-        if dfd_end < dfd_start || dfd_end as usize >= input.as_ref().len() {
-            return Err(ParseError::UnexpectedEnd);
-        }
-
-        // Check SGD bounds
-        if header
-            .index
-            .sgd_byte_offset
-            .checked_add(header.index.sgd_byte_length)
-            .ok_or(ParseError::UnexpectedEnd)?
-            >= input.as_ref().len() as u64
-        {
-            return Err(ParseError::UnexpectedEnd);
-        }
-
-        // Check KVD bounds
-        if header
-            .index
-            .kvd_byte_offset
-            .checked_add(header.index.kvd_byte_length)
-            .ok_or(ParseError::UnexpectedEnd)? as usize
-            >= input.as_ref().len()
-        {
-            return Err(ParseError::UnexpectedEnd);
-        }
-
         let result = Self { input, header };
         let index = result.level_index()?; // Check index integrity
-
-        // Check level data bounds
-        for level in index {
-            if level
-                .byte_offset
-                .checked_add(level.byte_length)
-                .ok_or(ParseError::UnexpectedEnd)?
-                > result.input.as_ref().len() as u64
-            {
-                return Err(ParseError::UnexpectedEnd);
-            }
-        }
+        check_bounds(&header, index, result.input.as_ref().len() as u64)?;
 
         Ok(result)
     }
 
     fn level_index(&self) -> ParseResult<impl ExactSizeIterator<Item = LevelIndex> + '_> {
-        let level_count = self.header().level_count.max(1) as usize;
-
-        let level_index_end_byte = Header::LENGTH
-            .checked_add(
-                level_count
-                    .checked_mul(LevelIndex::LENGTH)
-                    .ok_or(ParseError::UnexpectedEnd)?,
-            )
-            .ok_or(ParseError::UnexpectedEnd)?;
-        let level_index_bytes = self
-            .input
-            .as_ref()
-            .get(Header::LENGTH..level_index_end_byte)
-            .ok_or(ParseError::UnexpectedEnd)?;
-        Ok(level_index_bytes.chunks_exact(LevelIndex::LENGTH).map(|data| {
-            let level_data = data.try_into().unwrap();
-            LevelIndex::from_bytes(&level_data)
-        }))
+        parse_level_index(&self.header, self.input.as_ref())
     }
 
     /// Access underlying raw bytes
@@ -138,7 +153,7 @@ impl<Data: AsRef<[u8]>> Reader<Data> {
     }
 
     /// Iterator over the texture's mip levels
-    pub fn levels(&self) -> impl ExactSizeIterator<Item = Level> + '_ {
+    pub fn levels(&self) -> impl ExactSizeIterator<Item = Level<'_>> + '_ {
         self.level_index().unwrap().map(move |level| Level {
             // Bounds-checking previously performed in `new`
             data: &self.input.as_ref()[level.byte_offset as usize..(level.byte_offset + level.byte_length) as usize],
@@ -154,7 +169,7 @@ impl<Data: AsRef<[u8]>> Reader<Data> {
         &self.input.as_ref()[start..end]
     }
 
-    pub fn data_format_descriptors(&self) -> impl Iterator<Item = DataFormatDescriptor> {
+    pub fn data_format_descriptors(&self) -> impl Iterator<Item = DataFormatDescriptor<'_>> {
         let header = self.header();
         let start = header.index.dfd_byte_offset as usize;
         // Bounds-checking previously performed in `new`
@@ -166,7 +181,7 @@ impl<Data: AsRef<[u8]>> Reader<Data> {
     }
 
     /// Iterator over the key-value pairs
-    pub fn key_value_data(&self) -> KeyValueDataIterator {
+    pub fn key_value_data(&self) -> KeyValueDataIterator<'_> {
         let header = self.header();
 
         let start = header.index.kvd_byte_offset as usize;
@@ -191,7 +206,8 @@ impl<'data> Iterator for DataFormatDescriptorIterator<'data> {
         DataFormatDescriptorHeader::parse(&self.data[..DataFormatDescriptorHeader::LENGTH]).map_or(
             None,
             |(header, descriptor_block_size)| {
-                if descriptor_block_size == 0 || self.data.len() < descriptor_block_size {
+                if descriptor_block_size < DataFormatDescriptorHeader::LENGTH || self.data.len() < descriptor_block_size
+                {
                     return None;
                 }
                 let data = &self.data[DataFormatDescriptorHeader::LENGTH..descriptor_block_size];
@@ -251,7 +267,7 @@ impl<'data> Iterator for KeyValueDataIterator<'data> {
             let key = &key_and_value[..key_end_index];
             let value = &key_and_value[key_end_index + 1..];
 
-            let key = match std::str::from_utf8(key) {
+            let key = match core::str::from_utf8(key) {
                 Ok(key) => key,
                 Err(_) => continue,
             };
@@ -269,6 +285,83 @@ impl<'data> Iterator for KeyValueDataIterator<'data> {
     }
 }
 
+/// Parse the level index following the header at the start of `data`.
+fn parse_level_index<'data>(
+    header: &Header,
+    data: &'data [u8],
+) -> ParseResult<impl ExactSizeIterator<Item = LevelIndex> + 'data> {
+    let level_count = header.level_count.max(1) as usize;
+
+    let level_index_end_byte = Header::LENGTH
+        .checked_add(
+            level_count
+                .checked_mul(LevelIndex::LENGTH)
+                .ok_or(ParseError::UnexpectedEnd)?,
+        )
+        .ok_or(ParseError::UnexpectedEnd)?;
+    let level_index_bytes = data
+        .get(Header::LENGTH..level_index_end_byte)
+        .ok_or(ParseError::UnexpectedEnd)?;
+    Ok(level_index_bytes.chunks_exact(LevelIndex::LENGTH).map(|data| {
+        let level_data = data.try_into().unwrap();
+        LevelIndex::from_bytes(&level_data)
+    }))
+}
+
+/// Check that every section and level lies within a file of `length` bytes.
+fn check_bounds(header: &Header, levels: impl Iterator<Item = LevelIndex>, length: u64) -> ParseResult<()> {
+    // Check DFD bounds
+    let dfd_start = header
+        .index
+        .dfd_byte_offset
+        .checked_add(4)
+        .ok_or(ParseError::UnexpectedEnd)?;
+    let dfd_end = header
+        .index
+        .dfd_byte_offset
+        .checked_add(header.index.dfd_byte_length)
+        .ok_or(ParseError::UnexpectedEnd)?;
+    if dfd_end < dfd_start || dfd_end as u64 >= length {
+        return Err(ParseError::UnexpectedEnd);
+    }
+
+    // Check SGD bounds
+    if header
+        .index
+        .sgd_byte_offset
+        .checked_add(header.index.sgd_byte_length)
+        .ok_or(ParseError::UnexpectedEnd)?
+        >= length
+    {
+        return Err(ParseError::UnexpectedEnd);
+    }
+
+    // Check KVD bounds
+    if header
+        .index
+        .kvd_byte_offset
+        .checked_add(header.index.kvd_byte_length)
+        .ok_or(ParseError::UnexpectedEnd)? as u64
+        >= length
+    {
+        return Err(ParseError::UnexpectedEnd);
+    }
+
+    // Check level data bounds
+    for level in levels {
+        if level
+            .byte_offset
+            .checked_add(level.byte_length)
+            .ok_or(ParseError::UnexpectedEnd)?
+            > length
+        {
+            return Err(ParseError::UnexpectedEnd);
+        }
+    }
+
+    Ok(())
+}
+
 /// Identifier, expected in start of input texture data.
 const KTX2_MAGIC: [u8; 12] = [0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A];
 
@@ -401,7 +494,7 @@ impl LevelIndex {
 }
 
 bitflags::bitflags! {
-    #[derive(Debug)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
     #[repr(transparent)]
     pub struct ChannelTypeQualifiers: u32 {
         const LINEAR        = (1 << 0);
@@ -412,7 +505,7 @@ bitflags::bitflags! {
 }
 
 bitflags::bitflags! {
-    #[derive(Clone, Copy, Debug, Default)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
     #[repr(transparent)]
     pub struct DataFormatFlags: u32 {
         const STRAIGHT_ALPHA             = 0;
@@ -420,7 +513,7 @@ bitflags::bitflags! {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct DataFormatDescriptorHeader {
     pub vendor_id: u32,       //: 17;
     pub descriptor_type: u32, //: 15;
@@ -428,7 +521,7 @@ pub struct DataFormatDescriptorHeader {
 }
 
 impl DataFormatDescriptorHeader {
-    const LENGTH: usize = 8;
+    pub const LENGTH: usize = 8;
 
     pub const BASIC: Self = Self {
         vendor_id: 0,
@@ -456,6 +549,18 @@ impl DataFormatDescriptorHeader {
             descriptor_block_size as usize,
         ))
     }
+
+    /// Serializes the block header for a descriptor block of `descriptor_block_size` bytes, including this header.
+    pub fn as_bytes(&self, descriptor_block_size: u16) -> [u8; Self::LENGTH] {
+        let mut bytes = [0; Self::LENGTH];
+
+        let v = (self.vendor_id & 0x1FFFF) | ((self.descriptor_type & 0x7FFF) << 17);
+        bytes[0..4].copy_from_slice(&v.to_le_bytes()[..]);
+        let v = (self.version_number & 0xFFFF) | ((descriptor_block_size as u32) << 16);
+        bytes[4..8].copy_from_slice(&v.to_le_bytes()[..]);
+
+        bytes
+    }
 }
 
 pub struct DataFormatDescriptor<'data> {
@@ -463,7 +568,7 @@ pub struct DataFormatDescriptor<'data> {
     pub data: &'data [u8],
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct BasicDataFormatDescriptorHeader {
     /// None means Unspecified
     pub color_model: Option<ColorModel>, //: 8;
@@ -518,6 +623,27 @@ impl BasicDataFormatDescriptorHeader {
             bytes_planes,
         })
     }
+
+    /// Serializes the header. Texel block dimensions must be in `1..=256` and bytes planes in `0..=255`.
+    pub fn as_bytes(&self) -> [u8; Self::LENGTH] {
+        let mut bytes = [0; Self::LENGTH];
+
+        let model = self.color_model.map(|model| model.0.get()).unwrap_or(0);
+        let primaries = self.color_primaries.map(|primaries| primaries.0.get()).unwrap_or(0);
+        let transfer = self.transfer_function.map(|transfer| transfer.0.get()).unwrap_or(0);
+        bytes[0] = model as u8;
+        bytes[1] = primaries as u8;
+        bytes[2] = transfer as u8;
+        bytes[3] = self.flags.bits() as u8;
+        for (byte, dimension) in bytes[4..8].iter_mut().zip(&self.texel_block_dimensions) {
+            *byte = dimension.wrapping_sub(1) as u8;
+        }
+        for (byte, plane) in bytes[8..16].iter_mut().zip(&self.bytes_planes) {
+            *byte = *plane as u8;
+        }
+
+        bytes
+    }
 }
 
 pub struct BasicDataFormatDescriptor<'data> {
@@ -560,7 +686,7 @@ impl<'data> Iterator for SampleInformationIterator<'data> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct SampleInformation {
     pub bit_offset: u32,                                //: 16;
     pub bit_length: u32,                                //: 8;
@@ -572,7 +698,7 @@ pub struct SampleInformation {
 }
 
 impl SampleInformation {
-    const LENGTH: usize = 16;
+    pub const LENGTH: usize = 16;
 
     fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
         let mut offset = 0;
@@ -603,6 +729,24 @@ impl SampleInformation {
             upper,
         })
     }
+
+    /// Serializes the sample. Bit lengths must be in `1..=256`.
+    pub fn as_bytes(&self) -> [u8; Self::LENGTH] {
+        let mut bytes = [0; Self::LENGTH];
+
+        let v = (self.bit_offset & 0xFFFF)
+            | ((self.bit_length.wrapping_sub(1) & 0xFF) << 16)
+            | ((self.channel_type & 0xF) << 24)
+            | (self.channel_type_qualifiers.bits() << 28);
+        bytes[0..4].copy_from_slice(&v.to_le_bytes()[..]);
+        for (byte, position) in bytes[4..8].iter_mut().zip(&self.sample_positions) {
+            *byte = *position as u8;
+        }
+        bytes[8..12].copy_from_slice(&self.lower.to_le_bytes()[..]);
+        bytes[12..16].copy_from_slice(&self.upper.to_le_bytes()[..]);
+
+        bytes
+    }
 }
 
 fn bytes_to_u32(bytes: &[u8], offset: &mut usize) -> Result<u32, ParseError> {
@@ -621,6 +765,28 @@ fn shift_and_mask_lower(shift: u32, mask: u32, value: u32) -> u32 {
     (value >> shift) & ((1 << mask) - 1)
 }
 
+/// Required alignment of level data, given the data format descriptor blocks.
+///
+/// Levels are aligned to lcm(texel block size, 4), unless the file is supercompressed.
+#[cfg(feature = "alloc")]
+fn level_alignment(supercompression_scheme: Option<SupercompressionScheme>, dfd: &[u8]) -> usize {
+    if supercompression_scheme.is_some() {
+        return 1;
+    }
+    let texel_block_size = DataFormatDescriptorIterator { data: dfd }
+        .next()
+        .and_then(|dfd| BasicDataFormatDescriptorHeader::parse(dfd.data).ok())
+        .map_or(1, |header| (header.bytes_planes[0] as usize).max(1));
+
+    let (mut a, mut b) = (texel_block_size, 4);
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    texel_block_size / a * 4
+}
+
 #[test]
 #[allow(clippy::octal_escapes)]
 fn test_malformed_key_value_data_handling() {
@@ -0,0 +1,271 @@
+//! Typed values of the [standard keys](https://github.khronos.org/KTX-Specification/#_keyvalue_data).
+
+use crate::{Header, Reader};
+use core::convert::TryInto;
+
+/// Direction of increasing x texel coordinates
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrientationX {
+    Right,
+    Left,
+}
+
+/// Direction of increasing y texel coordinates
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrientationY {
+    Down,
+    Up,
+}
+
+/// Direction of increasing z texel coordinates
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrientationZ {
+    Out,
+    In,
+}
+
+/// Value of the `KTXorientation` key. Axes beyond the texture's dimensions are `None`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub x: OrientationX,
+    pub y: Option<OrientationY>,
+    pub z: Option<OrientationZ>,
+}
+
+impl Orientation {
+    /// Parse a value describing a texture with `dimensions` dimensions, such as `b"rd\0"`.
+    pub fn parse(value: &[u8], dimensions: usize) -> Option<Self> {
+        let axes = string(value)?.as_bytes();
+        if axes.len() != dimensions {
+            return None;
+        }
+        let x = match axes.first()? {
+            b'r' => OrientationX::Right,
+            b'l' => OrientationX::Left,
+            _ => return None,
+        };
+        let y = match axes.get(1) {
+            Some(b'd') => Some(OrientationY::Down),
+            Some(b'u') => Some(OrientationY::Up),
+            Some(_) => return None,
+            None => None,
+        };
+        let z = match axes.get(2) {
+            Some(b'o') => Some(OrientationZ::Out),
+            Some(b'i') => Some(OrientationZ::In),
+            Some(_) => return None,
+            None => None,
+        };
+        Some(Self { x, y, z })
+    }
+}
+
+/// Source of a component in the `KTXswizzle` key
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Swizzle {
+    R,
+    G,
+    B,
+    A,
+    Zero,
+    One,
+}
+
+impl Swizzle {
+    /// Parse a value such as `b"rgb1\0"`, giving the source of the red, green, blue and alpha components.
+    pub fn parse(value: &[u8]) -> Option<[Self; 4]> {
+        let components: &[u8; 4] = string(value)?.as_bytes().try_into().ok()?;
+        let mut swizzle = [Swizzle::Zero; 4];
+        for (swizzle, component) in swizzle.iter_mut().zip(components) {
+            *swizzle = match component {
+                b'r' => Swizzle::R,
+                b'g' => Swizzle::G,
+                b'b' => Swizzle::B,
+                b'a' => Swizzle::A,
+                b'0' => Swizzle::Zero,
+                b'1' => Swizzle::One,
+                _ => return None,
+            };
+        }
+        Some(swizzle)
+    }
+}
+
+/// Value of the `KTXastcDecodeMode` key
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AstcDecodeMode {
+    Rgb9e5,
+    Unorm8,
+}
+
+impl AstcDecodeMode {
+    pub fn parse(value: &[u8]) -> Option<Self> {
+        match string(value)? {
+            "rgb9e5" => Some(AstcDecodeMode::Rgb9e5),
+            "unorm8" => Some(AstcDecodeMode::Unorm8),
+            _ => None,
+        }
+    }
+}
+
+/// Value of the `KTXanimData` key, describing an array texture whose layers are animation frames
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AnimData {
+    /// Duration of each frame, in units of `timescale`
+    pub duration: u32,
+    /// Number of time units per second
+    pub timescale: u32,
+    /// Number of times to play the animation, or 0 to loop forever
+    pub loop_count: u32,
+}
+
+impl AnimData {
+    pub const LENGTH: usize = 12;
+
+    pub fn from_bytes(data: &[u8; Self::LENGTH]) -> Self {
+        Self {
+            duration: u32::from_le_bytes(data[0..4].try_into().unwrap()),
+            timescale: u32::from_le_bytes(data[4..8].try_into().unwrap()),
+            loop_count: u32::from_le_bytes(data[8..12].try_into().unwrap()),
+        }
+    }
+
+    pub fn as_bytes(&self) -> [u8; Self::LENGTH] {
+        let mut bytes = [0; Self::LENGTH];
+
+        bytes[0..4].copy_from_slice(&self.duration.to_le_bytes()[..]);
+        bytes[4..8].copy_from_slice(&self.timescale.to_le_bytes()[..]);
+        bytes[8..12].copy_from_slice(&self.loop_count.to_le_bytes()[..]);
+
+        bytes
+    }
+}
+
+bitflags::bitflags! {
+    /// Faces present in a texture with the `KTXcubemapIncomplete` key
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct CubemapFaces: u8 {
+        const POSITIVE_X = (1 << 0);
+        const NEGATIVE_X = (1 << 1);
+        const POSITIVE_Y = (1 << 2);
+        const NEGATIVE_Y = (1 << 3);
+        const POSITIVE_Z = (1 << 4);
+        const NEGATIVE_Z = (1 << 5);
+    }
+}
+
+/// Typed accessors for the standard keys. Each returns `None` if the key is missing or its value is malformed.
+impl<Data: AsRef<[u8]>> Reader<Data> {
+    /// Value of the first pair with `key`
+    pub fn key_value(&self, key: &str) -> Option<&[u8]> {
+        self.key_value_data()
+            .find(|&(pair_key, _)| pair_key == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn orientation(&self) -> Option<Orientation> {
+        Orientation::parse(self.key_value("KTXorientation")?, dimensions(&self.header()))
+    }
+
+    /// Name and version of the tool that wrote the file
+    pub fn writer(&self) -> Option<&str> {
+        string(self.key_value("KTXwriter")?)
+    }
+
+    /// Options used by the writer to supercompress the file
+    pub fn writer_sc_params(&self) -> Option<&str> {
+        string(self.key_value("KTXwriterScParams")?)
+    }
+
+    pub fn swizzle(&self) -> Option<[Swizzle; 4]> {
+        Swizzle::parse(self.key_value("KTXswizzle")?)
+    }
+
+    pub fn astc_decode_mode(&self) -> Option<AstcDecodeMode> {
+        AstcDecodeMode::parse(self.key_value("KTXastcDecodeMode")?)
+    }
+
+    pub fn anim_data(&self) -> Option<AnimData> {
+        Some(AnimData::from_bytes(self.key_value("KTXanimData")?.try_into().ok()?))
+    }
+
+    pub fn cubemap_incomplete(&self) -> Option<CubemapFaces> {
+        cubemap_faces(self.key_value("KTXcubemapIncomplete")?)
+    }
+}
+
+/// Whether `value` follows the format the specification requires for `key`. Unknown keys are always valid.
+#[cfg(feature = "alloc")]
+pub(crate) fn is_valid_value(key: &str, value: &[u8], header: &Header) -> bool {
+    match key {
+        "KTXorientation" => Orientation::parse(value, dimensions(header)).is_some(),
+        "KTXwriter" | "KTXwriterScParams" => string(value).is_some(),
+        "KTXswizzle" => Swizzle::parse(value).is_some(),
+        "KTXastcDecodeMode" => AstcDecodeMode::parse(value).is_some(),
+        "KTXanimData" => value.len() == AnimData::LENGTH,
+        "KTXcubemapIncomplete" => cubemap_faces(value).is_some(),
+        "KTXglFormat" => value.len() == crate::GlFormat::LENGTH,
+        "KTXdxgiFormat__" => {
+            value.len() == 4 && crate::DxgiFormat::new(u32::from_le_bytes(value.try_into().unwrap())).is_some()
+        }
+        "KTXmetalPixelFormat" => {
+            value.len() == 4 && crate::MetalPixelFormat::new(u32::from_le_bytes(value.try_into().unwrap())).is_some()
+        }
+        _ => true,
+    }
+}
+
+/// UTF-8 string with a single NUL terminator
+fn string(value: &[u8]) -> Option<&str> {
+    match value.split_last() {
+        Some((0, string)) if !string.contains(&0) => core::str::from_utf8(string).ok(),
+        _ => None,
+    }
+}
+
+fn cubemap_faces(value: &[u8]) -> Option<CubemapFaces> {
+    match value {
+        &[faces] => CubemapFaces::from_bits(faces),
+        _ => None,
+    }
+}
+
+fn dimensions(header: &Header) -> usize {
+    1 + (header.pixel_height > 0) as usize + (header.pixel_depth > 0) as usize
+}
+
+#[test]
+fn test_parse_standard_values() {
+    assert_eq!(
+        Orientation::parse(b"rd\0", 2),
+        Some(Orientation {
+            x: OrientationX::Right,
+            y: Some(OrientationY::Down),
+            z: None
+        })
+    );
+    assert_eq!(Orientation::parse(b"rd\0", 3), None);
+    assert_eq!(Orientation::parse(b"lui", 3), None);
+    assert_eq!(
+        Swizzle::parse(b"rg01\0"),
+        Some([Swizzle::R, Swizzle::G, Swizzle::Zero, Swizzle::One])
+    );
+    assert_eq!(Swizzle::parse(b"rgbx\0"), None);
+    assert_eq!(AstcDecodeMode::parse(b"unorm8\0"), Some(AstcDecodeMode::Unorm8));
+    assert_eq!(
+        cubemap_faces(&[0b11_0000]),
+        Some(CubemapFaces::POSITIVE_Z | CubemapFaces::NEGATIVE_Z)
+    );
+    assert_eq!(cubemap_faces(&[1 << 6]), None);
+    assert_eq!(string(b"a\0b\0"), None);
+
+    let file = crate::test_util::TEST_TEX;
+    let reader = Reader::new(file).unwrap();
+    assert!(reader.writer().unwrap().starts_with("ktx2ktx2"));
+    assert_eq!(
+        reader.orientation().map(|orientation| orientation.y),
+        Some(Some(OrientationY::Down))
+    );
+    assert_eq!(reader.anim_data(), None);
+}
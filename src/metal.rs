@@ -0,0 +1,322 @@
+use crate::{Format, MetalPixelFormat, Reader};
+use core::convert::TryInto;
+
+// Metal names packed formats starting from the least significant bit, so `R5G6B5_UNORM_PACK16` is
+// `B5G6R5Unorm`.
+impl Format {
+    /// Equivalent `MTLPixelFormat`, if any.
+    ///
+    /// ```rust
+    /// # use ktx2::{Format, MetalPixelFormat};
+    /// assert_eq!(Format::R8G8B8A8_UNORM.to_metal(), Some(MetalPixelFormat::RGBA8Unorm));
+    /// assert_eq!(Format::ASTC_4x4_SFLOAT_BLOCK.to_metal(), Some(MetalPixelFormat::ASTC_4x4_HDR));
+    /// ```
+    pub const fn to_metal(self) -> Option<MetalPixelFormat> {
+        Some(match self {
+            Format::R8_UNORM => MetalPixelFormat::R8Unorm,
+            Format::R8_SRGB => MetalPixelFormat::R8Unorm_sRGB,
+            Format::R8_SNORM => MetalPixelFormat::R8Snorm,
+            Format::R8_UINT => MetalPixelFormat::R8Uint,
+            Format::R8_SINT => MetalPixelFormat::R8Sint,
+            Format::R8G8_UNORM => MetalPixelFormat::RG8Unorm,
+            Format::R8G8_SRGB => MetalPixelFormat::RG8Unorm_sRGB,
+            Format::R8G8_SNORM => MetalPixelFormat::RG8Snorm,
+            Format::R8G8_UINT => MetalPixelFormat::RG8Uint,
+            Format::R8G8_SINT => MetalPixelFormat::RG8Sint,
+            Format::R8G8B8A8_UNORM => MetalPixelFormat::RGBA8Unorm,
+            Format::R8G8B8A8_SRGB => MetalPixelFormat::RGBA8Unorm_sRGB,
+            Format::R8G8B8A8_SNORM => MetalPixelFormat::RGBA8Snorm,
+            Format::R8G8B8A8_UINT => MetalPixelFormat::RGBA8Uint,
+            Format::R8G8B8A8_SINT => MetalPixelFormat::RGBA8Sint,
+            Format::R16_UNORM => MetalPixelFormat::R16Unorm,
+            Format::R16_SNORM => MetalPixelFormat::R16Snorm,
+            Format::R16_UINT => MetalPixelFormat::R16Uint,
+            Format::R16_SINT => MetalPixelFormat::R16Sint,
+            Format::R16_SFLOAT => MetalPixelFormat::R16Float,
+            Format::R16G16_UNORM => MetalPixelFormat::RG16Unorm,
+            Format::R16G16_SNORM => MetalPixelFormat::RG16Snorm,
+            Format::R16G16_UINT => MetalPixelFormat::RG16Uint,
+            Format::R16G16_SINT => MetalPixelFormat::RG16Sint,
+            Format::R16G16_SFLOAT => MetalPixelFormat::RG16Float,
+            Format::R16G16B16A16_UNORM => MetalPixelFormat::RGBA16Unorm,
+            Format::R16G16B16A16_SNORM => MetalPixelFormat::RGBA16Snorm,
+            Format::R16G16B16A16_UINT => MetalPixelFormat::RGBA16Uint,
+            Format::R16G16B16A16_SINT => MetalPixelFormat::RGBA16Sint,
+            Format::R16G16B16A16_SFLOAT => MetalPixelFormat::RGBA16Float,
+            Format::R32_UINT => MetalPixelFormat::R32Uint,
+            Format::R32_SINT => MetalPixelFormat::R32Sint,
+            Format::R32_SFLOAT => MetalPixelFormat::R32Float,
+            Format::R32G32_UINT => MetalPixelFormat::RG32Uint,
+            Format::R32G32_SINT => MetalPixelFormat::RG32Sint,
+            Format::R32G32_SFLOAT => MetalPixelFormat::RG32Float,
+            Format::R32G32B32A32_UINT => MetalPixelFormat::RGBA32Uint,
+            Format::R32G32B32A32_SINT => MetalPixelFormat::RGBA32Sint,
+            Format::R32G32B32A32_SFLOAT => MetalPixelFormat::RGBA32Float,
+            Format::R5G6B5_UNORM_PACK16 => MetalPixelFormat::B5G6R5Unorm,
+            Format::R5G5B5A1_UNORM_PACK16 => MetalPixelFormat::A1BGR5Unorm,
+            Format::R4G4B4A4_UNORM_PACK16 => MetalPixelFormat::ABGR4Unorm,
+            Format::A1R5G5B5_UNORM_PACK16 => MetalPixelFormat::BGR5A1Unorm,
+            Format::B8G8R8A8_UNORM => MetalPixelFormat::BGRA8Unorm,
+            Format::B8G8R8A8_SRGB => MetalPixelFormat::BGRA8Unorm_sRGB,
+            Format::A2B10G10R10_UNORM_PACK32 => MetalPixelFormat::RGB10A2Unorm,
+            Format::A2B10G10R10_UINT_PACK32 => MetalPixelFormat::RGB10A2Uint,
+            Format::A2R10G10B10_UNORM_PACK32 => MetalPixelFormat::BGR10A2Unorm,
+            Format::B10G11R11_UFLOAT_PACK32 => MetalPixelFormat::RG11B10Float,
+            Format::E5B9G9R9_UFLOAT_PACK32 => MetalPixelFormat::RGB9E5Float,
+            Format::D16_UNORM => MetalPixelFormat::Depth16Unorm,
+            Format::D32_SFLOAT => MetalPixelFormat::Depth32Float,
+            Format::S8_UINT => MetalPixelFormat::Stencil8,
+            Format::D24_UNORM_S8_UINT => MetalPixelFormat::Depth24Unorm_Stencil8,
+            Format::D32_SFLOAT_S8_UINT => MetalPixelFormat::Depth32Float_Stencil8,
+            Format::BC1_RGBA_UNORM_BLOCK => MetalPixelFormat::BC1_RGBA,
+            Format::BC1_RGBA_SRGB_BLOCK => MetalPixelFormat::BC1_RGBA_sRGB,
+            Format::BC2_UNORM_BLOCK => MetalPixelFormat::BC2_RGBA,
+            Format::BC2_SRGB_BLOCK => MetalPixelFormat::BC2_RGBA_sRGB,
+            Format::BC3_UNORM_BLOCK => MetalPixelFormat::BC3_RGBA,
+            Format::BC3_SRGB_BLOCK => MetalPixelFormat::BC3_RGBA_sRGB,
+            Format::BC4_UNORM_BLOCK => MetalPixelFormat::BC4_RUnorm,
+            Format::BC4_SNORM_BLOCK => MetalPixelFormat::BC4_RSnorm,
+            Format::BC5_UNORM_BLOCK => MetalPixelFormat::BC5_RGUnorm,
+            Format::BC5_SNORM_BLOCK => MetalPixelFormat::BC5_RGSnorm,
+            Format::BC6H_UFLOAT_BLOCK => MetalPixelFormat::BC6H_RGBUfloat,
+            Format::BC6H_SFLOAT_BLOCK => MetalPixelFormat::BC6H_RGBFloat,
+            Format::BC7_UNORM_BLOCK => MetalPixelFormat::BC7_RGBAUnorm,
+            Format::BC7_SRGB_BLOCK => MetalPixelFormat::BC7_RGBAUnorm_sRGB,
+            Format::ETC2_R8G8B8_UNORM_BLOCK => MetalPixelFormat::ETC2_RGB8,
+            Format::ETC2_R8G8B8_SRGB_BLOCK => MetalPixelFormat::ETC2_RGB8_sRGB,
+            Format::ETC2_R8G8B8A1_UNORM_BLOCK => MetalPixelFormat::ETC2_RGB8A1,
+            Format::ETC2_R8G8B8A1_SRGB_BLOCK => MetalPixelFormat::ETC2_RGB8A1_sRGB,
+            Format::ETC2_R8G8B8A8_UNORM_BLOCK => MetalPixelFormat::EAC_RGBA8,
+            Format::ETC2_R8G8B8A8_SRGB_BLOCK => MetalPixelFormat::EAC_RGBA8_sRGB,
+            Format::EAC_R11_UNORM_BLOCK => MetalPixelFormat::EAC_R11Unorm,
+            Format::EAC_R11_SNORM_BLOCK => MetalPixelFormat::EAC_R11Snorm,
+            Format::EAC_R11G11_UNORM_BLOCK => MetalPixelFormat::EAC_RG11Unorm,
+            Format::EAC_R11G11_SNORM_BLOCK => MetalPixelFormat::EAC_RG11Snorm,
+            Format::ASTC_4x4_UNORM_BLOCK => MetalPixelFormat::ASTC_4x4_LDR,
+            Format::ASTC_4x4_SRGB_BLOCK => MetalPixelFormat::ASTC_4x4_sRGB,
+            Format::ASTC_4x4_SFLOAT_BLOCK => MetalPixelFormat::ASTC_4x4_HDR,
+            Format::ASTC_5x4_UNORM_BLOCK => MetalPixelFormat::ASTC_5x4_LDR,
+            Format::ASTC_5x4_SRGB_BLOCK => MetalPixelFormat::ASTC_5x4_sRGB,
+            Format::ASTC_5x4_SFLOAT_BLOCK => MetalPixelFormat::ASTC_5x4_HDR,
+            Format::ASTC_5x5_UNORM_BLOCK => MetalPixelFormat::ASTC_5x5_LDR,
+            Format::ASTC_5x5_SRGB_BLOCK => MetalPixelFormat::ASTC_5x5_sRGB,
+            Format::ASTC_5x5_SFLOAT_BLOCK => MetalPixelFormat::ASTC_5x5_HDR,
+            Format::ASTC_6x5_UNORM_BLOCK => MetalPixelFormat::ASTC_6x5_LDR,
+            Format::ASTC_6x5_SRGB_BLOCK => MetalPixelFormat::ASTC_6x5_sRGB,
+            Format::ASTC_6x5_SFLOAT_BLOCK => MetalPixelFormat::ASTC_6x5_HDR,
+            Format::ASTC_6x6_UNORM_BLOCK => MetalPixelFormat::ASTC_6x6_LDR,
+            Format::ASTC_6x6_SRGB_BLOCK => MetalPixelFormat::ASTC_6x6_sRGB,
+            Format::ASTC_6x6_SFLOAT_BLOCK => MetalPixelFormat::ASTC_6x6_HDR,
+            Format::ASTC_8x5_UNORM_BLOCK => MetalPixelFormat::ASTC_8x5_LDR,
+            Format::ASTC_8x5_SRGB_BLOCK => MetalPixelFormat::ASTC_8x5_sRGB,
+            Format::ASTC_8x5_SFLOAT_BLOCK => MetalPixelFormat::ASTC_8x5_HDR,
+            Format::ASTC_8x6_UNORM_BLOCK => MetalPixelFormat::ASTC_8x6_LDR,
+            Format::ASTC_8x6_SRGB_BLOCK => MetalPixelFormat::ASTC_8x6_sRGB,
+            Format::ASTC_8x6_SFLOAT_BLOCK => MetalPixelFormat::ASTC_8x6_HDR,
+            Format::ASTC_8x8_UNORM_BLOCK => MetalPixelFormat::ASTC_8x8_LDR,
+            Format::ASTC_8x8_SRGB_BLOCK => MetalPixelFormat::ASTC_8x8_sRGB,
+            Format::ASTC_8x8_SFLOAT_BLOCK => MetalPixelFormat::ASTC_8x8_HDR,
+            Format::ASTC_10x5_UNORM_BLOCK => MetalPixelFormat::ASTC_10x5_LDR,
+            Format::ASTC_10x5_SRGB_BLOCK => MetalPixelFormat::ASTC_10x5_sRGB,
+            Format::ASTC_10x5_SFLOAT_BLOCK => MetalPixelFormat::ASTC_10x5_HDR,
+            Format::ASTC_10x6_UNORM_BLOCK => MetalPixelFormat::ASTC_10x6_LDR,
+            Format::ASTC_10x6_SRGB_BLOCK => MetalPixelFormat::ASTC_10x6_sRGB,
+            Format::ASTC_10x6_SFLOAT_BLOCK => MetalPixelFormat::ASTC_10x6_HDR,
+            Format::ASTC_10x8_UNORM_BLOCK => MetalPixelFormat::ASTC_10x8_LDR,
+            Format::ASTC_10x8_SRGB_BLOCK => MetalPixelFormat::ASTC_10x8_sRGB,
+            Format::ASTC_10x8_SFLOAT_BLOCK => MetalPixelFormat::ASTC_10x8_HDR,
+            Format::ASTC_10x10_UNORM_BLOCK => MetalPixelFormat::ASTC_10x10_LDR,
+            Format::ASTC_10x10_SRGB_BLOCK => MetalPixelFormat::ASTC_10x10_sRGB,
+            Format::ASTC_10x10_SFLOAT_BLOCK => MetalPixelFormat::ASTC_10x10_HDR,
+            Format::ASTC_12x10_UNORM_BLOCK => MetalPixelFormat::ASTC_12x10_LDR,
+            Format::ASTC_12x10_SRGB_BLOCK => MetalPixelFormat::ASTC_12x10_sRGB,
+            Format::ASTC_12x10_SFLOAT_BLOCK => MetalPixelFormat::ASTC_12x10_HDR,
+            Format::ASTC_12x12_UNORM_BLOCK => MetalPixelFormat::ASTC_12x12_LDR,
+            Format::ASTC_12x12_SRGB_BLOCK => MetalPixelFormat::ASTC_12x12_sRGB,
+            Format::ASTC_12x12_SFLOAT_BLOCK => MetalPixelFormat::ASTC_12x12_HDR,
+            _ => return None,
+        })
+    }
+}
+
+impl MetalPixelFormat {
+    /// Equivalent format, if any.
+    pub const fn to_format(self) -> Option<Format> {
+        Some(match self {
+            MetalPixelFormat::R8Unorm => Format::R8_UNORM,
+            MetalPixelFormat::R8Unorm_sRGB => Format::R8_SRGB,
+            MetalPixelFormat::R8Snorm => Format::R8_SNORM,
+            MetalPixelFormat::R8Uint => Format::R8_UINT,
+            MetalPixelFormat::R8Sint => Format::R8_SINT,
+            MetalPixelFormat::RG8Unorm => Format::R8G8_UNORM,
+            MetalPixelFormat::RG8Unorm_sRGB => Format::R8G8_SRGB,
+            MetalPixelFormat::RG8Snorm => Format::R8G8_SNORM,
+            MetalPixelFormat::RG8Uint => Format::R8G8_UINT,
+            MetalPixelFormat::RG8Sint => Format::R8G8_SINT,
+            MetalPixelFormat::RGBA8Unorm => Format::R8G8B8A8_UNORM,
+            MetalPixelFormat::RGBA8Unorm_sRGB => Format::R8G8B8A8_SRGB,
+            MetalPixelFormat::RGBA8Snorm => Format::R8G8B8A8_SNORM,
+            MetalPixelFormat::RGBA8Uint => Format::R8G8B8A8_UINT,
+            MetalPixelFormat::RGBA8Sint => Format::R8G8B8A8_SINT,
+            MetalPixelFormat::R16Unorm => Format::R16_UNORM,
+            MetalPixelFormat::R16Snorm => Format::R16_SNORM,
+            MetalPixelFormat::R16Uint => Format::R16_UINT,
+            MetalPixelFormat::R16Sint => Format::R16_SINT,
+            MetalPixelFormat::R16Float => Format::R16_SFLOAT,
+            MetalPixelFormat::RG16Unorm => Format::R16G16_UNORM,
+            MetalPixelFormat::RG16Snorm => Format::R16G16_SNORM,
+            MetalPixelFormat::RG16Uint => Format::R16G16_UINT,
+            MetalPixelFormat::RG16Sint => Format::R16G16_SINT,
+            MetalPixelFormat::RG16Float => Format::R16G16_SFLOAT,
+            MetalPixelFormat::RGBA16Unorm => Format::R16G16B16A16_UNORM,
+            MetalPixelFormat::RGBA16Snorm => Format::R16G16B16A16_SNORM,
+            MetalPixelFormat::RGBA16Uint => Format::R16G16B16A16_UINT,
+            MetalPixelFormat::RGBA16Sint => Format::R16G16B16A16_SINT,
+            MetalPixelFormat::RGBA16Float => Format::R16G16B16A16_SFLOAT,
+            MetalPixelFormat::R32Uint => Format::R32_UINT,
+            MetalPixelFormat::R32Sint => Format::R32_SINT,
+            MetalPixelFormat::R32Float => Format::R32_SFLOAT,
+            MetalPixelFormat::RG32Uint => Format::R32G32_UINT,
+            MetalPixelFormat::RG32Sint => Format::R32G32_SINT,
+            MetalPixelFormat::RG32Float => Format::R32G32_SFLOAT,
+            MetalPixelFormat::RGBA32Uint => Format::R32G32B32A32_UINT,
+            MetalPixelFormat::RGBA32Sint => Format::R32G32B32A32_SINT,
+            MetalPixelFormat::RGBA32Float => Format::R32G32B32A32_SFLOAT,
+            MetalPixelFormat::B5G6R5Unorm => Format::R5G6B5_UNORM_PACK16,
+            MetalPixelFormat::A1BGR5Unorm => Format::R5G5B5A1_UNORM_PACK16,
+            MetalPixelFormat::ABGR4Unorm => Format::R4G4B4A4_UNORM_PACK16,
+            MetalPixelFormat::BGR5A1Unorm => Format::A1R5G5B5_UNORM_PACK16,
+            MetalPixelFormat::BGRA8Unorm => Format::B8G8R8A8_UNORM,
+            MetalPixelFormat::BGRA8Unorm_sRGB => Format::B8G8R8A8_SRGB,
+            MetalPixelFormat::RGB10A2Unorm => Format::A2B10G10R10_UNORM_PACK32,
+            MetalPixelFormat::RGB10A2Uint => Format::A2B10G10R10_UINT_PACK32,
+            MetalPixelFormat::BGR10A2Unorm => Format::A2R10G10B10_UNORM_PACK32,
+            MetalPixelFormat::RG11B10Float => Format::B10G11R11_UFLOAT_PACK32,
+            MetalPixelFormat::RGB9E5Float => Format::E5B9G9R9_UFLOAT_PACK32,
+            MetalPixelFormat::Depth16Unorm => Format::D16_UNORM,
+            MetalPixelFormat::Depth32Float => Format::D32_SFLOAT,
+            MetalPixelFormat::Stencil8 => Format::S8_UINT,
+            MetalPixelFormat::Depth24Unorm_Stencil8 => Format::D24_UNORM_S8_UINT,
+            MetalPixelFormat::Depth32Float_Stencil8 => Format::D32_SFLOAT_S8_UINT,
+            MetalPixelFormat::BC1_RGBA => Format::BC1_RGBA_UNORM_BLOCK,
+            MetalPixelFormat::BC1_RGBA_sRGB => Format::BC1_RGBA_SRGB_BLOCK,
+            MetalPixelFormat::BC2_RGBA => Format::BC2_UNORM_BLOCK,
+            MetalPixelFormat::BC2_RGBA_sRGB => Format::BC2_SRGB_BLOCK,
+            MetalPixelFormat::BC3_RGBA => Format::BC3_UNORM_BLOCK,
+            MetalPixelFormat::BC3_RGBA_sRGB => Format::BC3_SRGB_BLOCK,
+            MetalPixelFormat::BC4_RUnorm => Format::BC4_UNORM_BLOCK,
+            MetalPixelFormat::BC4_RSnorm => Format::BC4_SNORM_BLOCK,
+            MetalPixelFormat::BC5_RGUnorm => Format::BC5_UNORM_BLOCK,
+            MetalPixelFormat::BC5_RGSnorm => Format::BC5_SNORM_BLOCK,
+            MetalPixelFormat::BC6H_RGBUfloat => Format::BC6H_UFLOAT_BLOCK,
+            MetalPixelFormat::BC6H_RGBFloat => Format::BC6H_SFLOAT_BLOCK,
+            MetalPixelFormat::BC7_RGBAUnorm => Format::BC7_UNORM_BLOCK,
+            MetalPixelFormat::BC7_RGBAUnorm_sRGB => Format::BC7_SRGB_BLOCK,
+            MetalPixelFormat::ETC2_RGB8 => Format::ETC2_R8G8B8_UNORM_BLOCK,
+            MetalPixelFormat::ETC2_RGB8_sRGB => Format::ETC2_R8G8B8_SRGB_BLOCK,
+            MetalPixelFormat::ETC2_RGB8A1 => Format::ETC2_R8G8B8A1_UNORM_BLOCK,
+            MetalPixelFormat::ETC2_RGB8A1_sRGB => Format::ETC2_R8G8B8A1_SRGB_BLOCK,
+            MetalPixelFormat::EAC_RGBA8 => Format::ETC2_R8G8B8A8_UNORM_BLOCK,
+            MetalPixelFormat::EAC_RGBA8_sRGB => Format::ETC2_R8G8B8A8_SRGB_BLOCK,
+            MetalPixelFormat::EAC_R11Unorm => Format::EAC_R11_UNORM_BLOCK,
+            MetalPixelFormat::EAC_R11Snorm => Format::EAC_R11_SNORM_BLOCK,
+            MetalPixelFormat::EAC_RG11Unorm => Format::EAC_R11G11_UNORM_BLOCK,
+            MetalPixelFormat::EAC_RG11Snorm => Format::EAC_R11G11_SNORM_BLOCK,
+            MetalPixelFormat::ASTC_4x4_LDR => Format::ASTC_4x4_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_4x4_sRGB => Format::ASTC_4x4_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_4x4_HDR => Format::ASTC_4x4_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_5x4_LDR => Format::ASTC_5x4_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_5x4_sRGB => Format::ASTC_5x4_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_5x4_HDR => Format::ASTC_5x4_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_5x5_LDR => Format::ASTC_5x5_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_5x5_sRGB => Format::ASTC_5x5_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_5x5_HDR => Format::ASTC_5x5_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_6x5_LDR => Format::ASTC_6x5_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_6x5_sRGB => Format::ASTC_6x5_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_6x5_HDR => Format::ASTC_6x5_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_6x6_LDR => Format::ASTC_6x6_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_6x6_sRGB => Format::ASTC_6x6_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_6x6_HDR => Format::ASTC_6x6_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_8x5_LDR => Format::ASTC_8x5_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_8x5_sRGB => Format::ASTC_8x5_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_8x5_HDR => Format::ASTC_8x5_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_8x6_LDR => Format::ASTC_8x6_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_8x6_sRGB => Format::ASTC_8x6_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_8x6_HDR => Format::ASTC_8x6_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_8x8_LDR => Format::ASTC_8x8_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_8x8_sRGB => Format::ASTC_8x8_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_8x8_HDR => Format::ASTC_8x8_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_10x5_LDR => Format::ASTC_10x5_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_10x5_sRGB => Format::ASTC_10x5_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_10x5_HDR => Format::ASTC_10x5_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_10x6_LDR => Format::ASTC_10x6_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_10x6_sRGB => Format::ASTC_10x6_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_10x6_HDR => Format::ASTC_10x6_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_10x8_LDR => Format::ASTC_10x8_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_10x8_sRGB => Format::ASTC_10x8_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_10x8_HDR => Format::ASTC_10x8_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_10x10_LDR => Format::ASTC_10x10_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_10x10_sRGB => Format::ASTC_10x10_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_10x10_HDR => Format::ASTC_10x10_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_12x10_LDR => Format::ASTC_12x10_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_12x10_sRGB => Format::ASTC_12x10_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_12x10_HDR => Format::ASTC_12x10_SFLOAT_BLOCK,
+            MetalPixelFormat::ASTC_12x12_LDR => Format::ASTC_12x12_UNORM_BLOCK,
+            MetalPixelFormat::ASTC_12x12_sRGB => Format::ASTC_12x12_SRGB_BLOCK,
+            MetalPixelFormat::ASTC_12x12_HDR => Format::ASTC_12x12_SFLOAT_BLOCK,
+            _ => return None,
+        })
+    }
+}
+
+impl<Data: AsRef<[u8]>> Reader<Data> {
+    /// Value of the `KTXmetalPixelFormat` key, if present and well-formed
+    pub fn metal_pixel_format(&self) -> Option<MetalPixelFormat> {
+        MetalPixelFormat::new(u32::from_le_bytes(
+            self.key_value("KTXmetalPixelFormat")?.try_into().ok()?,
+        ))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl crate::Writer<'_> {
+    /// Add a `KTXmetalPixelFormat` key with `format`.
+    pub fn metal_pixel_format(&mut self, format: MetalPixelFormat) -> &mut Self {
+        self.key_value_owned("KTXmetalPixelFormat", format.0.get().to_le_bytes().to_vec())
+    }
+}
+
+#[test]
+fn test_metal_pixel_format_round_trip() {
+    let mut count = 0;
+    for &format in Format::ALL {
+        if let Some(metal) = format.to_metal() {
+            assert_eq!(metal.to_format(), Some(format));
+            count += 1;
+        }
+    }
+    assert_eq!(count, 121);
+    assert_eq!(MetalPixelFormat::PVRTC_RGBA_4BPP.to_format(), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_write_metal_pixel_format() {
+    use crate::test_util::single_level_writer;
+
+    let dfd = crate::BasicDataFormatDescriptorBuilder::from_format(Format::BC7_SRGB_BLOCK)
+        .unwrap()
+        .build()
+        .unwrap();
+    let bytes = single_level_writer(Some(Format::BC7_SRGB_BLOCK), 4, &dfd, &[0; 16])
+        .metal_pixel_format(MetalPixelFormat::BC7_RGBAUnorm_sRGB)
+        .write()
+        .unwrap();
+
+    let reader = Reader::new(&bytes[..]).unwrap();
+    assert_eq!(reader.metal_pixel_format(), Some(MetalPixelFormat::BC7_RGBAUnorm_sRGB));
+    assert_eq!(
+        reader.key_value_data().collect::<alloc::vec::Vec<_>>(),
+        [("KTXmetalPixelFormat", &153u32.to_le_bytes()[..])]
+    );
+}
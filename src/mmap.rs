@@ -0,0 +1,59 @@
+use crate::{error::StreamError, Reader};
+use memmap2::Mmap;
+use std::{fs::File, path::Path};
+
+impl Reader<Mmap> {
+    /// Open the file at `path` by memory mapping it, so that [`Level::data`](crate::Level::data) and every other
+    /// slice borrow the mapping instead of a copy of the file.
+    ///
+    /// The same bounds checks as [`Reader::new`] are performed, so truncated files are rejected.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while it is mapped, see [`Mmap::map`].
+    ///
+    /// ```rust
+    /// let reader = unsafe { ktx2::Reader::open_mmap("data/test_tex.ktx2") }.unwrap();
+    /// assert_eq!(reader.levels().len(), 11);
+    /// ```
+    pub unsafe fn open_mmap(path: impl AsRef<Path>) -> Result<Self, StreamError> {
+        let file = File::open(path)?;
+        let mmap = Mmap::map(&file)?;
+        Ok(Reader::new(mmap)?)
+    }
+}
+
+#[test]
+fn test_open_mmap() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("data/test_tex.ktx2");
+    let file = std::fs::read(&path).unwrap();
+    let expected = Reader::new(&file[..]).unwrap();
+
+    let reader = unsafe { Reader::open_mmap(&path) }.unwrap();
+    assert_eq!(reader.header(), expected.header());
+    let mapping = reader.data().as_ptr_range();
+    for (level, expected) in reader.levels().zip(expected.levels()) {
+        assert_eq!(level.data, expected.data);
+        assert!(mapping.contains(&level.data.as_ptr()));
+    }
+
+    // Removes the file when dropped, even if an assertion fails
+    struct TempFile(std::path::PathBuf);
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    let name = std::format!("ktx2_test_open_mmap_truncated_{}.ktx2", std::process::id());
+    let truncated = TempFile(std::env::temp_dir().join(name));
+    std::fs::write(&truncated.0, &file[..file.len() / 2]).unwrap();
+    assert!(matches!(
+        unsafe { Reader::open_mmap(&truncated.0) },
+        Err(StreamError::Parse(crate::ParseError::UnexpectedEnd))
+    ));
+    assert!(matches!(
+        unsafe { Reader::open_mmap("data/missing.ktx2") },
+        Err(StreamError::Io(_))
+    ));
+}
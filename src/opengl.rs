@@ -0,0 +1,586 @@
+use crate::{Format, Reader};
+use core::convert::TryInto;
+
+/// OpenGL `glInternalformat`, `glFormat` and `glType` of a texture, as stored by the `KTXglFormat` key.
+///
+/// Compressed formats have a zero `format` and `data_type`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GlFormat {
+    pub internal_format: u32,
+    pub format: u32,
+    pub data_type: u32,
+}
+
+impl GlFormat {
+    pub const LENGTH: usize = 12;
+
+    pub fn from_bytes(data: &[u8; Self::LENGTH]) -> Self {
+        Self {
+            internal_format: u32::from_le_bytes(data[0..4].try_into().unwrap()),
+            format: u32::from_le_bytes(data[4..8].try_into().unwrap()),
+            data_type: u32::from_le_bytes(data[8..12].try_into().unwrap()),
+        }
+    }
+
+    pub fn as_bytes(&self) -> [u8; Self::LENGTH] {
+        let mut bytes = [0; Self::LENGTH];
+
+        bytes[0..4].copy_from_slice(&self.internal_format.to_le_bytes()[..]);
+        bytes[4..8].copy_from_slice(&self.format.to_le_bytes()[..]);
+        bytes[8..12].copy_from_slice(&self.data_type.to_le_bytes()[..]);
+
+        bytes
+    }
+
+    const fn uncompressed(internal_format: u32, format: u32, data_type: u32) -> Self {
+        Self {
+            internal_format,
+            format,
+            data_type,
+        }
+    }
+
+    const fn compressed(internal_format: u32) -> Self {
+        Self {
+            internal_format,
+            format: 0,
+            data_type: 0,
+        }
+    }
+
+    /// Equivalent format, if any.
+    ///
+    /// ASTC HDR formats share their GL internal formats with the LDR ones, so they map back to UNORM formats.
+    pub const fn to_format(self) -> Option<Format> {
+        Some(match (self.internal_format, self.format, self.data_type) {
+            (gl::R8, gl::RED, gl::UNSIGNED_BYTE) => Format::R8_UNORM,
+            (gl::R8_SNORM, gl::RED, gl::BYTE) => Format::R8_SNORM,
+            (gl::R8UI, gl::RED_INTEGER, gl::UNSIGNED_BYTE) => Format::R8_UINT,
+            (gl::R8I, gl::RED_INTEGER, gl::BYTE) => Format::R8_SINT,
+            (gl::SR8_EXT, gl::RED, gl::UNSIGNED_BYTE) => Format::R8_SRGB,
+            (gl::R16, gl::RED, gl::UNSIGNED_SHORT) => Format::R16_UNORM,
+            (gl::R16_SNORM, gl::RED, gl::SHORT) => Format::R16_SNORM,
+            (gl::R16UI, gl::RED_INTEGER, gl::UNSIGNED_SHORT) => Format::R16_UINT,
+            (gl::R16I, gl::RED_INTEGER, gl::SHORT) => Format::R16_SINT,
+            (gl::R16F, gl::RED, gl::HALF_FLOAT) => Format::R16_SFLOAT,
+            (gl::R32UI, gl::RED_INTEGER, gl::UNSIGNED_INT) => Format::R32_UINT,
+            (gl::R32I, gl::RED_INTEGER, gl::INT) => Format::R32_SINT,
+            (gl::R32F, gl::RED, gl::FLOAT) => Format::R32_SFLOAT,
+            (gl::RG8, gl::RG, gl::UNSIGNED_BYTE) => Format::R8G8_UNORM,
+            (gl::RG8_SNORM, gl::RG, gl::BYTE) => Format::R8G8_SNORM,
+            (gl::RG8UI, gl::RG_INTEGER, gl::UNSIGNED_BYTE) => Format::R8G8_UINT,
+            (gl::RG8I, gl::RG_INTEGER, gl::BYTE) => Format::R8G8_SINT,
+            (gl::SRG8_EXT, gl::RG, gl::UNSIGNED_BYTE) => Format::R8G8_SRGB,
+            (gl::RG16, gl::RG, gl::UNSIGNED_SHORT) => Format::R16G16_UNORM,
+            (gl::RG16_SNORM, gl::RG, gl::SHORT) => Format::R16G16_SNORM,
+            (gl::RG16UI, gl::RG_INTEGER, gl::UNSIGNED_SHORT) => Format::R16G16_UINT,
+            (gl::RG16I, gl::RG_INTEGER, gl::SHORT) => Format::R16G16_SINT,
+            (gl::RG16F, gl::RG, gl::HALF_FLOAT) => Format::R16G16_SFLOAT,
+            (gl::RG32UI, gl::RG_INTEGER, gl::UNSIGNED_INT) => Format::R32G32_UINT,
+            (gl::RG32I, gl::RG_INTEGER, gl::INT) => Format::R32G32_SINT,
+            (gl::RG32F, gl::RG, gl::FLOAT) => Format::R32G32_SFLOAT,
+            (gl::RGB8, gl::RGB, gl::UNSIGNED_BYTE) => Format::R8G8B8_UNORM,
+            (gl::RGB8_SNORM, gl::RGB, gl::BYTE) => Format::R8G8B8_SNORM,
+            (gl::RGB8UI, gl::RGB_INTEGER, gl::UNSIGNED_BYTE) => Format::R8G8B8_UINT,
+            (gl::RGB8I, gl::RGB_INTEGER, gl::BYTE) => Format::R8G8B8_SINT,
+            (gl::SRGB8, gl::RGB, gl::UNSIGNED_BYTE) => Format::R8G8B8_SRGB,
+            (gl::RGB16, gl::RGB, gl::UNSIGNED_SHORT) => Format::R16G16B16_UNORM,
+            (gl::RGB16_SNORM, gl::RGB, gl::SHORT) => Format::R16G16B16_SNORM,
+            (gl::RGB16UI, gl::RGB_INTEGER, gl::UNSIGNED_SHORT) => Format::R16G16B16_UINT,
+            (gl::RGB16I, gl::RGB_INTEGER, gl::SHORT) => Format::R16G16B16_SINT,
+            (gl::RGB16F, gl::RGB, gl::HALF_FLOAT) => Format::R16G16B16_SFLOAT,
+            (gl::RGB32UI, gl::RGB_INTEGER, gl::UNSIGNED_INT) => Format::R32G32B32_UINT,
+            (gl::RGB32I, gl::RGB_INTEGER, gl::INT) => Format::R32G32B32_SINT,
+            (gl::RGB32F, gl::RGB, gl::FLOAT) => Format::R32G32B32_SFLOAT,
+            (gl::RGB8, gl::BGR, gl::UNSIGNED_BYTE) => Format::B8G8R8_UNORM,
+            (gl::RGB8_SNORM, gl::BGR, gl::BYTE) => Format::B8G8R8_SNORM,
+            (gl::RGB8UI, gl::BGR_INTEGER, gl::UNSIGNED_BYTE) => Format::B8G8R8_UINT,
+            (gl::RGB8I, gl::BGR_INTEGER, gl::BYTE) => Format::B8G8R8_SINT,
+            (gl::SRGB8, gl::BGR, gl::UNSIGNED_BYTE) => Format::B8G8R8_SRGB,
+            (gl::RGBA8, gl::RGBA, gl::UNSIGNED_BYTE) => Format::R8G8B8A8_UNORM,
+            (gl::RGBA8_SNORM, gl::RGBA, gl::BYTE) => Format::R8G8B8A8_SNORM,
+            (gl::RGBA8UI, gl::RGBA_INTEGER, gl::UNSIGNED_BYTE) => Format::R8G8B8A8_UINT,
+            (gl::RGBA8I, gl::RGBA_INTEGER, gl::BYTE) => Format::R8G8B8A8_SINT,
+            (gl::SRGB8_ALPHA8, gl::RGBA, gl::UNSIGNED_BYTE) => Format::R8G8B8A8_SRGB,
+            (gl::RGBA16, gl::RGBA, gl::UNSIGNED_SHORT) => Format::R16G16B16A16_UNORM,
+            (gl::RGBA16_SNORM, gl::RGBA, gl::SHORT) => Format::R16G16B16A16_SNORM,
+            (gl::RGBA16UI, gl::RGBA_INTEGER, gl::UNSIGNED_SHORT) => Format::R16G16B16A16_UINT,
+            (gl::RGBA16I, gl::RGBA_INTEGER, gl::SHORT) => Format::R16G16B16A16_SINT,
+            (gl::RGBA16F, gl::RGBA, gl::HALF_FLOAT) => Format::R16G16B16A16_SFLOAT,
+            (gl::RGBA32UI, gl::RGBA_INTEGER, gl::UNSIGNED_INT) => Format::R32G32B32A32_UINT,
+            (gl::RGBA32I, gl::RGBA_INTEGER, gl::INT) => Format::R32G32B32A32_SINT,
+            (gl::RGBA32F, gl::RGBA, gl::FLOAT) => Format::R32G32B32A32_SFLOAT,
+            (gl::RGBA8, gl::BGRA, gl::UNSIGNED_BYTE) => Format::B8G8R8A8_UNORM,
+            (gl::RGBA8_SNORM, gl::BGRA, gl::BYTE) => Format::B8G8R8A8_SNORM,
+            (gl::RGBA8UI, gl::BGRA_INTEGER, gl::UNSIGNED_BYTE) => Format::B8G8R8A8_UINT,
+            (gl::RGBA8I, gl::BGRA_INTEGER, gl::BYTE) => Format::B8G8R8A8_SINT,
+            (gl::SRGB8_ALPHA8, gl::BGRA, gl::UNSIGNED_BYTE) => Format::B8G8R8A8_SRGB,
+            (gl::RGBA4, gl::RGBA, gl::UNSIGNED_SHORT_4_4_4_4) => Format::R4G4B4A4_UNORM_PACK16,
+            (gl::RGBA4, gl::BGRA, gl::UNSIGNED_SHORT_4_4_4_4) => Format::B4G4R4A4_UNORM_PACK16,
+            (gl::RGB565, gl::RGB, gl::UNSIGNED_SHORT_5_6_5) => Format::R5G6B5_UNORM_PACK16,
+            (gl::RGB565, gl::RGB, gl::UNSIGNED_SHORT_5_6_5_REV) => Format::B5G6R5_UNORM_PACK16,
+            (gl::RGB5_A1, gl::RGBA, gl::UNSIGNED_SHORT_5_5_5_1) => Format::R5G5B5A1_UNORM_PACK16,
+            (gl::RGB5_A1, gl::BGRA, gl::UNSIGNED_SHORT_5_5_5_1) => Format::B5G5R5A1_UNORM_PACK16,
+            (gl::RGB5_A1, gl::BGRA, gl::UNSIGNED_SHORT_1_5_5_5_REV) => Format::A1R5G5B5_UNORM_PACK16,
+            (gl::RGB10_A2, gl::BGRA, gl::UNSIGNED_INT_2_10_10_10_REV) => Format::A2R10G10B10_UNORM_PACK32,
+            (gl::RGB10_A2UI, gl::BGRA_INTEGER, gl::UNSIGNED_INT_2_10_10_10_REV) => Format::A2R10G10B10_UINT_PACK32,
+            (gl::RGB10_A2, gl::RGBA, gl::UNSIGNED_INT_2_10_10_10_REV) => Format::A2B10G10R10_UNORM_PACK32,
+            (gl::RGB10_A2UI, gl::RGBA_INTEGER, gl::UNSIGNED_INT_2_10_10_10_REV) => Format::A2B10G10R10_UINT_PACK32,
+            (gl::R11F_G11F_B10F, gl::RGB, gl::UNSIGNED_INT_10F_11F_11F_REV) => Format::B10G11R11_UFLOAT_PACK32,
+            (gl::RGB9_E5, gl::RGB, gl::UNSIGNED_INT_5_9_9_9_REV) => Format::E5B9G9R9_UFLOAT_PACK32,
+            (gl::DEPTH_COMPONENT16, gl::DEPTH_COMPONENT, gl::UNSIGNED_SHORT) => Format::D16_UNORM,
+            (gl::DEPTH_COMPONENT24, gl::DEPTH_COMPONENT, gl::UNSIGNED_INT) => Format::X8_D24_UNORM_PACK32,
+            (gl::DEPTH_COMPONENT32F, gl::DEPTH_COMPONENT, gl::FLOAT) => Format::D32_SFLOAT,
+            (gl::STENCIL_INDEX8, gl::STENCIL_INDEX, gl::UNSIGNED_BYTE) => Format::S8_UINT,
+            (gl::DEPTH24_STENCIL8, gl::DEPTH_STENCIL, gl::UNSIGNED_INT_24_8) => Format::D24_UNORM_S8_UINT,
+            (gl::DEPTH32F_STENCIL8, gl::DEPTH_STENCIL, gl::FLOAT_32_UNSIGNED_INT_24_8_REV) => {
+                Format::D32_SFLOAT_S8_UINT
+            }
+            (gl::COMPRESSED_RGB_S3TC_DXT1_EXT, 0, 0) => Format::BC1_RGB_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB_S3TC_DXT1_EXT, 0, 0) => Format::BC1_RGB_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_S3TC_DXT1_EXT, 0, 0) => Format::BC1_RGBA_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT, 0, 0) => Format::BC1_RGBA_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_S3TC_DXT3_EXT, 0, 0) => Format::BC2_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT, 0, 0) => Format::BC2_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_S3TC_DXT5_EXT, 0, 0) => Format::BC3_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT, 0, 0) => Format::BC3_SRGB_BLOCK,
+            (gl::COMPRESSED_RED_RGTC1, 0, 0) => Format::BC4_UNORM_BLOCK,
+            (gl::COMPRESSED_SIGNED_RED_RGTC1, 0, 0) => Format::BC4_SNORM_BLOCK,
+            (gl::COMPRESSED_RG_RGTC2, 0, 0) => Format::BC5_UNORM_BLOCK,
+            (gl::COMPRESSED_SIGNED_RG_RGTC2, 0, 0) => Format::BC5_SNORM_BLOCK,
+            (gl::COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT, 0, 0) => Format::BC6H_UFLOAT_BLOCK,
+            (gl::COMPRESSED_RGB_BPTC_SIGNED_FLOAT, 0, 0) => Format::BC6H_SFLOAT_BLOCK,
+            (gl::COMPRESSED_RGBA_BPTC_UNORM, 0, 0) => Format::BC7_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB_ALPHA_BPTC_UNORM, 0, 0) => Format::BC7_SRGB_BLOCK,
+            (gl::COMPRESSED_RGB8_ETC2, 0, 0) => Format::ETC2_R8G8B8_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ETC2, 0, 0) => Format::ETC2_R8G8B8_SRGB_BLOCK,
+            (gl::COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2, 0, 0) => Format::ETC2_R8G8B8A1_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2, 0, 0) => Format::ETC2_R8G8B8A1_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA8_ETC2_EAC, 0, 0) => Format::ETC2_R8G8B8A8_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ETC2_EAC, 0, 0) => Format::ETC2_R8G8B8A8_SRGB_BLOCK,
+            (gl::COMPRESSED_R11_EAC, 0, 0) => Format::EAC_R11_UNORM_BLOCK,
+            (gl::COMPRESSED_SIGNED_R11_EAC, 0, 0) => Format::EAC_R11_SNORM_BLOCK,
+            (gl::COMPRESSED_RG11_EAC, 0, 0) => Format::EAC_R11G11_UNORM_BLOCK,
+            (gl::COMPRESSED_SIGNED_RG11_EAC, 0, 0) => Format::EAC_R11G11_SNORM_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_4x4_KHR, 0, 0) => Format::ASTC_4x4_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_4x4_KHR, 0, 0) => Format::ASTC_4x4_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_5x4_KHR, 0, 0) => Format::ASTC_5x4_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_5x4_KHR, 0, 0) => Format::ASTC_5x4_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_5x5_KHR, 0, 0) => Format::ASTC_5x5_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_5x5_KHR, 0, 0) => Format::ASTC_5x5_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_6x5_KHR, 0, 0) => Format::ASTC_6x5_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_6x5_KHR, 0, 0) => Format::ASTC_6x5_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_6x6_KHR, 0, 0) => Format::ASTC_6x6_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_6x6_KHR, 0, 0) => Format::ASTC_6x6_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_8x5_KHR, 0, 0) => Format::ASTC_8x5_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_8x5_KHR, 0, 0) => Format::ASTC_8x5_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_8x6_KHR, 0, 0) => Format::ASTC_8x6_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_8x6_KHR, 0, 0) => Format::ASTC_8x6_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_8x8_KHR, 0, 0) => Format::ASTC_8x8_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_8x8_KHR, 0, 0) => Format::ASTC_8x8_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_10x5_KHR, 0, 0) => Format::ASTC_10x5_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_10x5_KHR, 0, 0) => Format::ASTC_10x5_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_10x6_KHR, 0, 0) => Format::ASTC_10x6_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_10x6_KHR, 0, 0) => Format::ASTC_10x6_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_10x8_KHR, 0, 0) => Format::ASTC_10x8_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_10x8_KHR, 0, 0) => Format::ASTC_10x8_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_10x10_KHR, 0, 0) => Format::ASTC_10x10_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_10x10_KHR, 0, 0) => Format::ASTC_10x10_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_12x10_KHR, 0, 0) => Format::ASTC_12x10_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_12x10_KHR, 0, 0) => Format::ASTC_12x10_SRGB_BLOCK,
+            (gl::COMPRESSED_RGBA_ASTC_12x12_KHR, 0, 0) => Format::ASTC_12x12_UNORM_BLOCK,
+            (gl::COMPRESSED_SRGB8_ALPHA8_ASTC_12x12_KHR, 0, 0) => Format::ASTC_12x12_SRGB_BLOCK,
+            _ => return None,
+        })
+    }
+}
+
+impl Format {
+    /// Equivalent OpenGL internal format, format and type, if any.
+    ///
+    /// ```rust
+    /// # use ktx2::{Format, GlFormat};
+    /// let gl = Format::R8G8B8A8_SRGB.to_gl().unwrap();
+    /// // GL_SRGB8_ALPHA8, GL_RGBA, GL_UNSIGNED_BYTE
+    /// assert_eq!((gl.internal_format, gl.format, gl.data_type), (0x8C43, 0x1908, 0x1401));
+    /// assert_eq!(gl.to_format(), Some(Format::R8G8B8A8_SRGB));
+    /// ```
+    pub const fn to_gl(self) -> Option<GlFormat> {
+        Some(match self {
+            Format::R8_UNORM => GlFormat::uncompressed(gl::R8, gl::RED, gl::UNSIGNED_BYTE),
+            Format::R8_SNORM => GlFormat::uncompressed(gl::R8_SNORM, gl::RED, gl::BYTE),
+            Format::R8_UINT => GlFormat::uncompressed(gl::R8UI, gl::RED_INTEGER, gl::UNSIGNED_BYTE),
+            Format::R8_SINT => GlFormat::uncompressed(gl::R8I, gl::RED_INTEGER, gl::BYTE),
+            Format::R8_SRGB => GlFormat::uncompressed(gl::SR8_EXT, gl::RED, gl::UNSIGNED_BYTE),
+            Format::R16_UNORM => GlFormat::uncompressed(gl::R16, gl::RED, gl::UNSIGNED_SHORT),
+            Format::R16_SNORM => GlFormat::uncompressed(gl::R16_SNORM, gl::RED, gl::SHORT),
+            Format::R16_UINT => GlFormat::uncompressed(gl::R16UI, gl::RED_INTEGER, gl::UNSIGNED_SHORT),
+            Format::R16_SINT => GlFormat::uncompressed(gl::R16I, gl::RED_INTEGER, gl::SHORT),
+            Format::R16_SFLOAT => GlFormat::uncompressed(gl::R16F, gl::RED, gl::HALF_FLOAT),
+            Format::R32_UINT => GlFormat::uncompressed(gl::R32UI, gl::RED_INTEGER, gl::UNSIGNED_INT),
+            Format::R32_SINT => GlFormat::uncompressed(gl::R32I, gl::RED_INTEGER, gl::INT),
+            Format::R32_SFLOAT => GlFormat::uncompressed(gl::R32F, gl::RED, gl::FLOAT),
+            Format::R8G8_UNORM => GlFormat::uncompressed(gl::RG8, gl::RG, gl::UNSIGNED_BYTE),
+            Format::R8G8_SNORM => GlFormat::uncompressed(gl::RG8_SNORM, gl::RG, gl::BYTE),
+            Format::R8G8_UINT => GlFormat::uncompressed(gl::RG8UI, gl::RG_INTEGER, gl::UNSIGNED_BYTE),
+            Format::R8G8_SINT => GlFormat::uncompressed(gl::RG8I, gl::RG_INTEGER, gl::BYTE),
+            Format::R8G8_SRGB => GlFormat::uncompressed(gl::SRG8_EXT, gl::RG, gl::UNSIGNED_BYTE),
+            Format::R16G16_UNORM => GlFormat::uncompressed(gl::RG16, gl::RG, gl::UNSIGNED_SHORT),
+            Format::R16G16_SNORM => GlFormat::uncompressed(gl::RG16_SNORM, gl::RG, gl::SHORT),
+            Format::R16G16_UINT => GlFormat::uncompressed(gl::RG16UI, gl::RG_INTEGER, gl::UNSIGNED_SHORT),
+            Format::R16G16_SINT => GlFormat::uncompressed(gl::RG16I, gl::RG_INTEGER, gl::SHORT),
+            Format::R16G16_SFLOAT => GlFormat::uncompressed(gl::RG16F, gl::RG, gl::HALF_FLOAT),
+            Format::R32G32_UINT => GlFormat::uncompressed(gl::RG32UI, gl::RG_INTEGER, gl::UNSIGNED_INT),
+            Format::R32G32_SINT => GlFormat::uncompressed(gl::RG32I, gl::RG_INTEGER, gl::INT),
+            Format::R32G32_SFLOAT => GlFormat::uncompressed(gl::RG32F, gl::RG, gl::FLOAT),
+            Format::R8G8B8_UNORM => GlFormat::uncompressed(gl::RGB8, gl::RGB, gl::UNSIGNED_BYTE),
+            Format::R8G8B8_SNORM => GlFormat::uncompressed(gl::RGB8_SNORM, gl::RGB, gl::BYTE),
+            Format::R8G8B8_UINT => GlFormat::uncompressed(gl::RGB8UI, gl::RGB_INTEGER, gl::UNSIGNED_BYTE),
+            Format::R8G8B8_SINT => GlFormat::uncompressed(gl::RGB8I, gl::RGB_INTEGER, gl::BYTE),
+            Format::R8G8B8_SRGB => GlFormat::uncompressed(gl::SRGB8, gl::RGB, gl::UNSIGNED_BYTE),
+            Format::R16G16B16_UNORM => GlFormat::uncompressed(gl::RGB16, gl::RGB, gl::UNSIGNED_SHORT),
+            Format::R16G16B16_SNORM => GlFormat::uncompressed(gl::RGB16_SNORM, gl::RGB, gl::SHORT),
+            Format::R16G16B16_UINT => GlFormat::uncompressed(gl::RGB16UI, gl::RGB_INTEGER, gl::UNSIGNED_SHORT),
+            Format::R16G16B16_SINT => GlFormat::uncompressed(gl::RGB16I, gl::RGB_INTEGER, gl::SHORT),
+            Format::R16G16B16_SFLOAT => GlFormat::uncompressed(gl::RGB16F, gl::RGB, gl::HALF_FLOAT),
+            Format::R32G32B32_UINT => GlFormat::uncompressed(gl::RGB32UI, gl::RGB_INTEGER, gl::UNSIGNED_INT),
+            Format::R32G32B32_SINT => GlFormat::uncompressed(gl::RGB32I, gl::RGB_INTEGER, gl::INT),
+            Format::R32G32B32_SFLOAT => GlFormat::uncompressed(gl::RGB32F, gl::RGB, gl::FLOAT),
+            Format::B8G8R8_UNORM => GlFormat::uncompressed(gl::RGB8, gl::BGR, gl::UNSIGNED_BYTE),
+            Format::B8G8R8_SNORM => GlFormat::uncompressed(gl::RGB8_SNORM, gl::BGR, gl::BYTE),
+            Format::B8G8R8_UINT => GlFormat::uncompressed(gl::RGB8UI, gl::BGR_INTEGER, gl::UNSIGNED_BYTE),
+            Format::B8G8R8_SINT => GlFormat::uncompressed(gl::RGB8I, gl::BGR_INTEGER, gl::BYTE),
+            Format::B8G8R8_SRGB => GlFormat::uncompressed(gl::SRGB8, gl::BGR, gl::UNSIGNED_BYTE),
+            Format::R8G8B8A8_UNORM => GlFormat::uncompressed(gl::RGBA8, gl::RGBA, gl::UNSIGNED_BYTE),
+            Format::R8G8B8A8_SNORM => GlFormat::uncompressed(gl::RGBA8_SNORM, gl::RGBA, gl::BYTE),
+            Format::R8G8B8A8_UINT => GlFormat::uncompressed(gl::RGBA8UI, gl::RGBA_INTEGER, gl::UNSIGNED_BYTE),
+            Format::R8G8B8A8_SINT => GlFormat::uncompressed(gl::RGBA8I, gl::RGBA_INTEGER, gl::BYTE),
+            Format::R8G8B8A8_SRGB => GlFormat::uncompressed(gl::SRGB8_ALPHA8, gl::RGBA, gl::UNSIGNED_BYTE),
+            Format::R16G16B16A16_UNORM => GlFormat::uncompressed(gl::RGBA16, gl::RGBA, gl::UNSIGNED_SHORT),
+            Format::R16G16B16A16_SNORM => GlFormat::uncompressed(gl::RGBA16_SNORM, gl::RGBA, gl::SHORT),
+            Format::R16G16B16A16_UINT => GlFormat::uncompressed(gl::RGBA16UI, gl::RGBA_INTEGER, gl::UNSIGNED_SHORT),
+            Format::R16G16B16A16_SINT => GlFormat::uncompressed(gl::RGBA16I, gl::RGBA_INTEGER, gl::SHORT),
+            Format::R16G16B16A16_SFLOAT => GlFormat::uncompressed(gl::RGBA16F, gl::RGBA, gl::HALF_FLOAT),
+            Format::R32G32B32A32_UINT => GlFormat::uncompressed(gl::RGBA32UI, gl::RGBA_INTEGER, gl::UNSIGNED_INT),
+            Format::R32G32B32A32_SINT => GlFormat::uncompressed(gl::RGBA32I, gl::RGBA_INTEGER, gl::INT),
+            Format::R32G32B32A32_SFLOAT => GlFormat::uncompressed(gl::RGBA32F, gl::RGBA, gl::FLOAT),
+            Format::B8G8R8A8_UNORM => GlFormat::uncompressed(gl::RGBA8, gl::BGRA, gl::UNSIGNED_BYTE),
+            Format::B8G8R8A8_SNORM => GlFormat::uncompressed(gl::RGBA8_SNORM, gl::BGRA, gl::BYTE),
+            Format::B8G8R8A8_UINT => GlFormat::uncompressed(gl::RGBA8UI, gl::BGRA_INTEGER, gl::UNSIGNED_BYTE),
+            Format::B8G8R8A8_SINT => GlFormat::uncompressed(gl::RGBA8I, gl::BGRA_INTEGER, gl::BYTE),
+            Format::B8G8R8A8_SRGB => GlFormat::uncompressed(gl::SRGB8_ALPHA8, gl::BGRA, gl::UNSIGNED_BYTE),
+            Format::R4G4B4A4_UNORM_PACK16 => GlFormat::uncompressed(gl::RGBA4, gl::RGBA, gl::UNSIGNED_SHORT_4_4_4_4),
+            Format::B4G4R4A4_UNORM_PACK16 => GlFormat::uncompressed(gl::RGBA4, gl::BGRA, gl::UNSIGNED_SHORT_4_4_4_4),
+            Format::R5G6B5_UNORM_PACK16 => GlFormat::uncompressed(gl::RGB565, gl::RGB, gl::UNSIGNED_SHORT_5_6_5),
+            Format::B5G6R5_UNORM_PACK16 => GlFormat::uncompressed(gl::RGB565, gl::RGB, gl::UNSIGNED_SHORT_5_6_5_REV),
+            Format::R5G5B5A1_UNORM_PACK16 => GlFormat::uncompressed(gl::RGB5_A1, gl::RGBA, gl::UNSIGNED_SHORT_5_5_5_1),
+            Format::B5G5R5A1_UNORM_PACK16 => GlFormat::uncompressed(gl::RGB5_A1, gl::BGRA, gl::UNSIGNED_SHORT_5_5_5_1),
+            Format::A1R5G5B5_UNORM_PACK16 => {
+                GlFormat::uncompressed(gl::RGB5_A1, gl::BGRA, gl::UNSIGNED_SHORT_1_5_5_5_REV)
+            }
+            Format::A2R10G10B10_UNORM_PACK32 => {
+                GlFormat::uncompressed(gl::RGB10_A2, gl::BGRA, gl::UNSIGNED_INT_2_10_10_10_REV)
+            }
+            Format::A2R10G10B10_UINT_PACK32 => {
+                GlFormat::uncompressed(gl::RGB10_A2UI, gl::BGRA_INTEGER, gl::UNSIGNED_INT_2_10_10_10_REV)
+            }
+            Format::A2B10G10R10_UNORM_PACK32 => {
+                GlFormat::uncompressed(gl::RGB10_A2, gl::RGBA, gl::UNSIGNED_INT_2_10_10_10_REV)
+            }
+            Format::A2B10G10R10_UINT_PACK32 => {
+                GlFormat::uncompressed(gl::RGB10_A2UI, gl::RGBA_INTEGER, gl::UNSIGNED_INT_2_10_10_10_REV)
+            }
+            Format::B10G11R11_UFLOAT_PACK32 => {
+                GlFormat::uncompressed(gl::R11F_G11F_B10F, gl::RGB, gl::UNSIGNED_INT_10F_11F_11F_REV)
+            }
+            Format::E5B9G9R9_UFLOAT_PACK32 => {
+                GlFormat::uncompressed(gl::RGB9_E5, gl::RGB, gl::UNSIGNED_INT_5_9_9_9_REV)
+            }
+            Format::D16_UNORM => GlFormat::uncompressed(gl::DEPTH_COMPONENT16, gl::DEPTH_COMPONENT, gl::UNSIGNED_SHORT),
+            Format::X8_D24_UNORM_PACK32 => {
+                GlFormat::uncompressed(gl::DEPTH_COMPONENT24, gl::DEPTH_COMPONENT, gl::UNSIGNED_INT)
+            }
+            Format::D32_SFLOAT => GlFormat::uncompressed(gl::DEPTH_COMPONENT32F, gl::DEPTH_COMPONENT, gl::FLOAT),
+            Format::S8_UINT => GlFormat::uncompressed(gl::STENCIL_INDEX8, gl::STENCIL_INDEX, gl::UNSIGNED_BYTE),
+            Format::D24_UNORM_S8_UINT => {
+                GlFormat::uncompressed(gl::DEPTH24_STENCIL8, gl::DEPTH_STENCIL, gl::UNSIGNED_INT_24_8)
+            }
+            Format::D32_SFLOAT_S8_UINT => GlFormat::uncompressed(
+                gl::DEPTH32F_STENCIL8,
+                gl::DEPTH_STENCIL,
+                gl::FLOAT_32_UNSIGNED_INT_24_8_REV,
+            ),
+            Format::BC1_RGB_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGB_S3TC_DXT1_EXT),
+            Format::BC1_RGB_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB_S3TC_DXT1_EXT),
+            Format::BC1_RGBA_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_S3TC_DXT1_EXT),
+            Format::BC1_RGBA_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT),
+            Format::BC2_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_S3TC_DXT3_EXT),
+            Format::BC2_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT),
+            Format::BC3_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_S3TC_DXT5_EXT),
+            Format::BC3_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT),
+            Format::BC4_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RED_RGTC1),
+            Format::BC4_SNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_SIGNED_RED_RGTC1),
+            Format::BC5_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RG_RGTC2),
+            Format::BC5_SNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_SIGNED_RG_RGTC2),
+            Format::BC6H_UFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT),
+            Format::BC6H_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGB_BPTC_SIGNED_FLOAT),
+            Format::BC7_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_BPTC_UNORM),
+            Format::BC7_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB_ALPHA_BPTC_UNORM),
+            Format::ETC2_R8G8B8_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGB8_ETC2),
+            Format::ETC2_R8G8B8_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ETC2),
+            Format::ETC2_R8G8B8A1_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2),
+            Format::ETC2_R8G8B8A1_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2),
+            Format::ETC2_R8G8B8A8_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA8_ETC2_EAC),
+            Format::ETC2_R8G8B8A8_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ETC2_EAC),
+            Format::EAC_R11_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_R11_EAC),
+            Format::EAC_R11_SNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_SIGNED_R11_EAC),
+            Format::EAC_R11G11_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RG11_EAC),
+            Format::EAC_R11G11_SNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_SIGNED_RG11_EAC),
+            Format::ASTC_4x4_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_4x4_KHR),
+            Format::ASTC_4x4_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_4x4_KHR),
+            Format::ASTC_5x4_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_5x4_KHR),
+            Format::ASTC_5x4_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_5x4_KHR),
+            Format::ASTC_5x5_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_5x5_KHR),
+            Format::ASTC_5x5_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_5x5_KHR),
+            Format::ASTC_6x5_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_6x5_KHR),
+            Format::ASTC_6x5_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_6x5_KHR),
+            Format::ASTC_6x6_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_6x6_KHR),
+            Format::ASTC_6x6_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_6x6_KHR),
+            Format::ASTC_8x5_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_8x5_KHR),
+            Format::ASTC_8x5_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_8x5_KHR),
+            Format::ASTC_8x6_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_8x6_KHR),
+            Format::ASTC_8x6_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_8x6_KHR),
+            Format::ASTC_8x8_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_8x8_KHR),
+            Format::ASTC_8x8_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_8x8_KHR),
+            Format::ASTC_10x5_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_10x5_KHR),
+            Format::ASTC_10x5_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_10x5_KHR),
+            Format::ASTC_10x6_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_10x6_KHR),
+            Format::ASTC_10x6_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_10x6_KHR),
+            Format::ASTC_10x8_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_10x8_KHR),
+            Format::ASTC_10x8_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_10x8_KHR),
+            Format::ASTC_10x10_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_10x10_KHR),
+            Format::ASTC_10x10_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_10x10_KHR),
+            Format::ASTC_12x10_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_12x10_KHR),
+            Format::ASTC_12x10_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_12x10_KHR),
+            Format::ASTC_12x12_UNORM_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_12x12_KHR),
+            Format::ASTC_12x12_SRGB_BLOCK => GlFormat::compressed(gl::COMPRESSED_SRGB8_ALPHA8_ASTC_12x12_KHR),
+            Format::ASTC_4x4_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_4x4_KHR),
+            Format::ASTC_5x4_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_5x4_KHR),
+            Format::ASTC_5x5_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_5x5_KHR),
+            Format::ASTC_6x5_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_6x5_KHR),
+            Format::ASTC_6x6_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_6x6_KHR),
+            Format::ASTC_8x5_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_8x5_KHR),
+            Format::ASTC_8x6_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_8x6_KHR),
+            Format::ASTC_8x8_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_8x8_KHR),
+            Format::ASTC_10x5_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_10x5_KHR),
+            Format::ASTC_10x6_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_10x6_KHR),
+            Format::ASTC_10x8_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_10x8_KHR),
+            Format::ASTC_10x10_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_10x10_KHR),
+            Format::ASTC_12x10_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_12x10_KHR),
+            Format::ASTC_12x12_SFLOAT_BLOCK => GlFormat::compressed(gl::COMPRESSED_RGBA_ASTC_12x12_KHR),
+            _ => return None,
+        })
+    }
+}
+
+impl<Data: AsRef<[u8]>> Reader<Data> {
+    /// OpenGL format of the texture, converted from the header's format, or read from the `KTXglFormat` key if the
+    /// format is undefined.
+    pub fn gl_format(&self) -> Option<GlFormat> {
+        if let Some(format) = self.header().format {
+            return format.to_gl();
+        }
+        Some(GlFormat::from_bytes(self.key_value("KTXglFormat")?.try_into().ok()?))
+    }
+}
+
+/// OpenGL enum values, without the `GL_` prefix
+#[allow(non_upper_case_globals)]
+mod gl {
+    pub const RED: u32 = 0x1903;
+    pub const RG: u32 = 0x8227;
+    pub const RGB: u32 = 0x1907;
+    pub const RGBA: u32 = 0x1908;
+    pub const BGR: u32 = 0x80E0;
+    pub const BGRA: u32 = 0x80E1;
+    pub const RED_INTEGER: u32 = 0x8D94;
+    pub const RG_INTEGER: u32 = 0x8228;
+    pub const RGB_INTEGER: u32 = 0x8D98;
+    pub const RGBA_INTEGER: u32 = 0x8D99;
+    pub const BGR_INTEGER: u32 = 0x8D9A;
+    pub const BGRA_INTEGER: u32 = 0x8D9B;
+    pub const DEPTH_COMPONENT: u32 = 0x1902;
+    pub const STENCIL_INDEX: u32 = 0x1901;
+    pub const DEPTH_STENCIL: u32 = 0x84F9;
+    pub const BYTE: u32 = 0x1400;
+    pub const UNSIGNED_BYTE: u32 = 0x1401;
+    pub const SHORT: u32 = 0x1402;
+    pub const UNSIGNED_SHORT: u32 = 0x1403;
+    pub const INT: u32 = 0x1404;
+    pub const UNSIGNED_INT: u32 = 0x1405;
+    pub const FLOAT: u32 = 0x1406;
+    pub const HALF_FLOAT: u32 = 0x140B;
+    pub const UNSIGNED_SHORT_4_4_4_4: u32 = 0x8033;
+    pub const UNSIGNED_SHORT_5_5_5_1: u32 = 0x8034;
+    pub const UNSIGNED_SHORT_5_6_5: u32 = 0x8363;
+    pub const UNSIGNED_SHORT_5_6_5_REV: u32 = 0x8364;
+    pub const UNSIGNED_SHORT_1_5_5_5_REV: u32 = 0x8366;
+    pub const UNSIGNED_INT_2_10_10_10_REV: u32 = 0x8368;
+    pub const UNSIGNED_INT_24_8: u32 = 0x84FA;
+    pub const UNSIGNED_INT_10F_11F_11F_REV: u32 = 0x8C3B;
+    pub const UNSIGNED_INT_5_9_9_9_REV: u32 = 0x8C3E;
+    pub const FLOAT_32_UNSIGNED_INT_24_8_REV: u32 = 0x8DAD;
+    pub const R8: u32 = 0x8229;
+    pub const R8_SNORM: u32 = 0x8F94;
+    pub const R8UI: u32 = 0x8232;
+    pub const R8I: u32 = 0x8231;
+    pub const SR8_EXT: u32 = 0x8FBD;
+    pub const RG8: u32 = 0x822B;
+    pub const RG8_SNORM: u32 = 0x8F95;
+    pub const RG8UI: u32 = 0x8238;
+    pub const RG8I: u32 = 0x8237;
+    pub const SRG8_EXT: u32 = 0x8FBE;
+    pub const RGB8: u32 = 0x8051;
+    pub const RGB8_SNORM: u32 = 0x8F96;
+    pub const RGB8UI: u32 = 0x8D7D;
+    pub const RGB8I: u32 = 0x8D8F;
+    pub const SRGB8: u32 = 0x8C41;
+    pub const RGBA8: u32 = 0x8058;
+    pub const RGBA8_SNORM: u32 = 0x8F97;
+    pub const RGBA8UI: u32 = 0x8D7C;
+    pub const RGBA8I: u32 = 0x8D8E;
+    pub const SRGB8_ALPHA8: u32 = 0x8C43;
+    pub const R16: u32 = 0x822A;
+    pub const R16_SNORM: u32 = 0x8F98;
+    pub const R16UI: u32 = 0x8234;
+    pub const R16I: u32 = 0x8233;
+    pub const R16F: u32 = 0x822D;
+    pub const RG16: u32 = 0x822C;
+    pub const RG16_SNORM: u32 = 0x8F99;
+    pub const RG16UI: u32 = 0x823A;
+    pub const RG16I: u32 = 0x8239;
+    pub const RG16F: u32 = 0x822F;
+    pub const RGB16: u32 = 0x8054;
+    pub const RGB16_SNORM: u32 = 0x8F9A;
+    pub const RGB16UI: u32 = 0x8D77;
+    pub const RGB16I: u32 = 0x8D89;
+    pub const RGB16F: u32 = 0x881B;
+    pub const RGBA16: u32 = 0x805B;
+    pub const RGBA16_SNORM: u32 = 0x8F9B;
+    pub const RGBA16UI: u32 = 0x8D76;
+    pub const RGBA16I: u32 = 0x8D88;
+    pub const RGBA16F: u32 = 0x881A;
+    pub const R32UI: u32 = 0x8236;
+    pub const R32I: u32 = 0x8235;
+    pub const R32F: u32 = 0x822E;
+    pub const RG32UI: u32 = 0x823C;
+    pub const RG32I: u32 = 0x823B;
+    pub const RG32F: u32 = 0x8230;
+    pub const RGB32UI: u32 = 0x8D71;
+    pub const RGB32I: u32 = 0x8D83;
+    pub const RGB32F: u32 = 0x8815;
+    pub const RGBA32UI: u32 = 0x8D70;
+    pub const RGBA32I: u32 = 0x8D82;
+    pub const RGBA32F: u32 = 0x8814;
+    pub const RGBA4: u32 = 0x8056;
+    pub const RGB5_A1: u32 = 0x8057;
+    pub const RGB565: u32 = 0x8D62;
+    pub const RGB10_A2: u32 = 0x8059;
+    pub const RGB10_A2UI: u32 = 0x906F;
+    pub const R11F_G11F_B10F: u32 = 0x8C3A;
+    pub const RGB9_E5: u32 = 0x8C3D;
+    pub const DEPTH_COMPONENT16: u32 = 0x81A5;
+    pub const DEPTH_COMPONENT24: u32 = 0x81A6;
+    pub const DEPTH_COMPONENT32F: u32 = 0x8CAC;
+    pub const STENCIL_INDEX8: u32 = 0x8D48;
+    pub const DEPTH24_STENCIL8: u32 = 0x88F0;
+    pub const DEPTH32F_STENCIL8: u32 = 0x8CAD;
+    pub const COMPRESSED_RGB_S3TC_DXT1_EXT: u32 = 0x83F0;
+    pub const COMPRESSED_RGBA_S3TC_DXT1_EXT: u32 = 0x83F1;
+    pub const COMPRESSED_RGBA_S3TC_DXT3_EXT: u32 = 0x83F2;
+    pub const COMPRESSED_RGBA_S3TC_DXT5_EXT: u32 = 0x83F3;
+    pub const COMPRESSED_SRGB_S3TC_DXT1_EXT: u32 = 0x8C4C;
+    pub const COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT: u32 = 0x8C4D;
+    pub const COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT: u32 = 0x8C4E;
+    pub const COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT: u32 = 0x8C4F;
+    pub const COMPRESSED_RED_RGTC1: u32 = 0x8DBB;
+    pub const COMPRESSED_SIGNED_RED_RGTC1: u32 = 0x8DBC;
+    pub const COMPRESSED_RG_RGTC2: u32 = 0x8DBD;
+    pub const COMPRESSED_SIGNED_RG_RGTC2: u32 = 0x8DBE;
+    pub const COMPRESSED_RGBA_BPTC_UNORM: u32 = 0x8E8C;
+    pub const COMPRESSED_SRGB_ALPHA_BPTC_UNORM: u32 = 0x8E8D;
+    pub const COMPRESSED_RGB_BPTC_SIGNED_FLOAT: u32 = 0x8E8E;
+    pub const COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT: u32 = 0x8E8F;
+    pub const COMPRESSED_R11_EAC: u32 = 0x9270;
+    pub const COMPRESSED_SIGNED_R11_EAC: u32 = 0x9271;
+    pub const COMPRESSED_RG11_EAC: u32 = 0x9272;
+    pub const COMPRESSED_SIGNED_RG11_EAC: u32 = 0x9273;
+    pub const COMPRESSED_RGB8_ETC2: u32 = 0x9274;
+    pub const COMPRESSED_SRGB8_ETC2: u32 = 0x9275;
+    pub const COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2: u32 = 0x9276;
+    pub const COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2: u32 = 0x9277;
+    pub const COMPRESSED_RGBA8_ETC2_EAC: u32 = 0x9278;
+    pub const COMPRESSED_SRGB8_ALPHA8_ETC2_EAC: u32 = 0x9279;
+    pub const COMPRESSED_RGBA_ASTC_4x4_KHR: u32 = 0x93B0;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_4x4_KHR: u32 = 0x93D0;
+    pub const COMPRESSED_RGBA_ASTC_5x4_KHR: u32 = 0x93B1;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_5x4_KHR: u32 = 0x93D1;
+    pub const COMPRESSED_RGBA_ASTC_5x5_KHR: u32 = 0x93B2;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_5x5_KHR: u32 = 0x93D2;
+    pub const COMPRESSED_RGBA_ASTC_6x5_KHR: u32 = 0x93B3;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_6x5_KHR: u32 = 0x93D3;
+    pub const COMPRESSED_RGBA_ASTC_6x6_KHR: u32 = 0x93B4;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_6x6_KHR: u32 = 0x93D4;
+    pub const COMPRESSED_RGBA_ASTC_8x5_KHR: u32 = 0x93B5;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_8x5_KHR: u32 = 0x93D5;
+    pub const COMPRESSED_RGBA_ASTC_8x6_KHR: u32 = 0x93B6;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_8x6_KHR: u32 = 0x93D6;
+    pub const COMPRESSED_RGBA_ASTC_8x8_KHR: u32 = 0x93B7;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_8x8_KHR: u32 = 0x93D7;
+    pub const COMPRESSED_RGBA_ASTC_10x5_KHR: u32 = 0x93B8;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_10x5_KHR: u32 = 0x93D8;
+    pub const COMPRESSED_RGBA_ASTC_10x6_KHR: u32 = 0x93B9;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_10x6_KHR: u32 = 0x93D9;
+    pub const COMPRESSED_RGBA_ASTC_10x8_KHR: u32 = 0x93BA;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_10x8_KHR: u32 = 0x93DA;
+    pub const COMPRESSED_RGBA_ASTC_10x10_KHR: u32 = 0x93BB;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_10x10_KHR: u32 = 0x93DB;
+    pub const COMPRESSED_RGBA_ASTC_12x10_KHR: u32 = 0x93BC;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_12x10_KHR: u32 = 0x93DC;
+    pub const COMPRESSED_RGBA_ASTC_12x12_KHR: u32 = 0x93BD;
+    pub const COMPRESSED_SRGB8_ALPHA8_ASTC_12x12_KHR: u32 = 0x93DD;
+}
+
+#[test]
+fn test_gl_format_round_trip() {
+    for &format in Format::ALL {
+        if let Some(gl) = format.to_gl() {
+            assert_eq!(gl.to_format().and_then(Format::to_gl), Some(gl), "{:?}", format);
+            assert_eq!(gl.format == 0, format.is_compressed(), "{:?}", format);
+        }
+    }
+    assert_eq!(
+        Format::ASTC_6x6_SFLOAT_BLOCK.to_gl().unwrap().to_format(),
+        Some(Format::ASTC_6x6_UNORM_BLOCK)
+    );
+    assert_eq!(Format::R64_SFLOAT.to_gl(), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_read_gl_format() {
+    use crate::test_util::{single_level_writer, test_tex_dfd, TEST_TEX};
+
+    let reader = Reader::new(TEST_TEX).unwrap();
+    assert_eq!(reader.gl_format(), Format::R8G8B8A8_UINT.to_gl());
+
+    let gl = Format::R8G8B8A8_UINT.to_gl().unwrap().as_bytes();
+    let bytes = single_level_writer(None, 1, test_tex_dfd(), &[0; 4])
+        .key_value("KTXglFormat", &gl)
+        .write()
+        .unwrap();
+    assert_eq!(
+        Reader::new(&bytes[..]).unwrap().gl_format(),
+        Some(GlFormat::from_bytes(&gl))
+    );
+}
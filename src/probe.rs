@@ -0,0 +1,126 @@
+use crate::{
+    parse_level_index, DataFormatDescriptor, DataFormatDescriptorIterator, Header, KeyValueDataIterator, LevelIndex,
+    ParseError, ParseResult,
+};
+use core::convert::TryInto;
+
+/// Container-level metadata parsed from the start of a file, without its level data.
+///
+/// Unlike [`Reader`](crate::Reader), only the header and level index must be present. The data format descriptor and
+/// key/value data are available if the input extends to [`Header::metadata_end`].
+///
+/// ```rust
+/// # use std::convert::TryInto;
+/// # let file = include_bytes!("../data/test_tex.ktx2");
+/// let header = ktx2::Header::from_bytes(file[..ktx2::Header::LENGTH].try_into().unwrap()).unwrap();
+/// let probe = ktx2::Probe::new(&file[..header.metadata_end() as usize]).unwrap();
+/// assert_eq!((probe.header().pixel_width, probe.header().pixel_height), (1024, 512));
+/// assert_eq!(probe.level_index().len(), 11);
+/// assert!(probe.data_format_descriptors().is_some());
+/// ```
+pub struct Probe<'data> {
+    data: &'data [u8],
+    header: Header,
+}
+
+impl<'data> Probe<'data> {
+    /// Parse the header and level index at the start of `data`, which may end anywhere after the level index
+    pub fn new(data: &'data [u8]) -> ParseResult<Self> {
+        let header_data = data
+            .get(..Header::LENGTH)
+            .ok_or(ParseError::UnexpectedEnd)?
+            .try_into()
+            .unwrap();
+        let header = Header::from_bytes(header_data)?;
+        let _ = parse_level_index(&header, data)?; // Check the level index is present
+        Ok(Self { data, header })
+    }
+
+    /// Container-level metadata
+    pub fn header(&self) -> Header {
+        self.header
+    }
+
+    /// Location of each level in the file, starting with the base level. The levels are not bounds-checked.
+    pub fn level_index(&self) -> impl ExactSizeIterator<Item = LevelIndex> + 'data {
+        // Presence checked in `new`
+        parse_level_index(&self.header, self.data).unwrap()
+    }
+
+    /// Data format descriptors, or `None` if they are not contained in the input
+    pub fn data_format_descriptors(&self) -> Option<impl Iterator<Item = DataFormatDescriptor<'data>>> {
+        let index = self.header.index;
+        // Skip the data format descriptors total length
+        let start = index.dfd_byte_offset.checked_add(4)? as usize;
+        let end = index.dfd_byte_offset.checked_add(index.dfd_byte_length)? as usize;
+        Some(DataFormatDescriptorIterator {
+            data: self.data.get(start..end)?,
+        })
+    }
+
+    /// Key-value pairs, or `None` if they are not contained in the input
+    pub fn key_value_data(&self) -> Option<KeyValueDataIterator<'data>> {
+        let index = self.header.index;
+        let start = index.kvd_byte_offset as usize;
+        let end = index.kvd_byte_offset.checked_add(index.kvd_byte_length)? as usize;
+        Some(KeyValueDataIterator::new(self.data.get(start..end)?))
+    }
+}
+
+impl Header {
+    /// Offset of the first byte after the level index.
+    pub fn level_index_end(&self) -> u64 {
+        Header::LENGTH as u64 + self.level_count.max(1) as u64 * LevelIndex::LENGTH as u64
+    }
+
+    /// Offset of the first byte after the level index, data format descriptor and key/value data, whichever ends
+    /// last. Reading this many bytes from the start of a file is enough for [`Probe`] to return all metadata.
+    pub fn metadata_end(&self) -> u64 {
+        let dfd_end = self.index.dfd_byte_offset as u64 + self.index.dfd_byte_length as u64;
+        let kvd_end = self.index.kvd_byte_offset as u64 + self.index.kvd_byte_length as u64;
+        self.level_index_end().max(dfd_end).max(kvd_end)
+    }
+}
+
+#[test]
+fn test_probe_prefix() {
+    use crate::Reader;
+
+    let file = crate::test_util::TEST_TEX;
+    let reader = Reader::new(file).unwrap();
+    let header = reader.header();
+    assert_eq!(header.level_index_end(), 80 + 11 * 24);
+
+    let probe = Probe::new(&file[..header.level_index_end() as usize]).unwrap();
+    assert_eq!(probe.header(), header);
+    assert!(probe
+        .level_index()
+        .map(|level| (level.byte_length, level.uncompressed_byte_length))
+        .eq(reader
+            .levels()
+            .map(|level| (level.data.len() as u64, level.uncompressed_byte_length))));
+    assert!(probe.data_format_descriptors().is_none());
+    assert!(probe.key_value_data().is_none());
+
+    let probe = Probe::new(&file[..header.metadata_end() as usize]).unwrap();
+    assert!(probe
+        .data_format_descriptors()
+        .unwrap()
+        .map(|dfd| (dfd.header, dfd.data))
+        .eq(reader.data_format_descriptors().map(|dfd| (dfd.header, dfd.data))));
+    assert!(probe.key_value_data().unwrap().eq(reader.key_value_data()));
+
+    // Descriptor block sizes shorter than the block header, or longer than the descriptor
+    let mut prefix = file[..header.metadata_end() as usize].to_vec();
+    let block_size = header.index.dfd_byte_offset as usize + 4 + 6;
+    for size in [0, 4, 7, u16::MAX] {
+        prefix[block_size..block_size + 2].copy_from_slice(&size.to_le_bytes());
+        let probe = Probe::new(&prefix).unwrap();
+        assert_eq!(probe.data_format_descriptors().unwrap().count(), 0);
+    }
+
+    assert!(matches!(
+        Probe::new(&file[..Header::LENGTH]),
+        Err(ParseError::UnexpectedEnd)
+    ));
+}
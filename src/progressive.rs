@@ -0,0 +1,221 @@
+use crate::{error::ChunkError, Header, Level, LevelIndex, ParseError, ParseResult, Probe};
+use alloc::{vec, vec::Vec};
+use core::ops::Range;
+
+/// Part of a texture that is fetched with a single range request.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Chunk {
+    /// Level index, data format descriptor and key/value data, following the header
+    Metadata,
+    SupercompressionGlobalData,
+    Level(u32),
+}
+
+/// Byte range of a chunk within the file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeRequest {
+    pub chunk: Chunk,
+    pub range: Range<u64>,
+}
+
+/// Assembles a texture from chunks fetched with range requests, e.g. over HTTP.
+///
+/// Starting from the header, [`requests`](Self::requests) lists the byte ranges still missing in the order they
+/// should be fetched: the metadata first, then the supercompression global data, then the levels from smallest to
+/// largest. Level ranges are known once the metadata has been pushed.
+///
+/// ```rust
+/// # use std::convert::TryInto;
+/// # let file = include_bytes!("../data/test_tex.ktx2");
+/// # let fetch = |range: std::ops::Range<u64>| &file[range.start as usize..range.end as usize];
+/// let mut reader = ktx2::ProgressiveReader::new(fetch(0..ktx2::Header::LENGTH as u64).try_into().unwrap()).unwrap();
+/// while let Some(request) = reader.next_request() {
+///     reader.push(request.chunk, fetch(request.range)).unwrap();
+///     // Coarse levels are available while the finer ones are still being fetched
+/// }
+/// assert!(reader.level(0).is_some());
+/// ```
+pub struct ProgressiveReader {
+    /// Header, followed by the metadata chunk once it has been pushed
+    metadata: Vec<u8>,
+    header: Header,
+    level_index: Vec<LevelIndex>,
+    supercompression_global_data: Option<Vec<u8>>,
+    levels: Vec<Option<Vec<u8>>>,
+}
+
+impl ProgressiveReader {
+    /// Start reading from the first [`Header::LENGTH`] bytes of a file
+    pub fn new(header_data: &[u8; Header::LENGTH]) -> ParseResult<Self> {
+        let header = Header::from_bytes(header_data)?;
+        Ok(Self {
+            metadata: header_data.to_vec(),
+            header,
+            level_index: Vec::new(),
+            supercompression_global_data: None,
+            levels: Vec::new(),
+        })
+    }
+
+    /// Container-level metadata
+    pub fn header(&self) -> Header {
+        self.header
+    }
+
+    /// Byte range of `chunk`, or `None` if it is unknown until the metadata has been pushed
+    pub fn range(&self, chunk: Chunk) -> Option<Range<u64>> {
+        match chunk {
+            Chunk::Metadata => Some(Header::LENGTH as u64..self.header.metadata_end()),
+            Chunk::SupercompressionGlobalData => {
+                let index = self.header.index;
+                Some(index.sgd_byte_offset..index.sgd_byte_offset.checked_add(index.sgd_byte_length)?)
+            }
+            Chunk::Level(level) => {
+                let index = self.level_index.get(level as usize)?;
+                Some(index.byte_offset..index.byte_offset + index.byte_length)
+            }
+        }
+    }
+
+    /// Chunks that haven't been pushed yet, in the order they should be fetched
+    pub fn requests(&self) -> impl Iterator<Item = RangeRequest> + '_ {
+        let metadata = (!self.has_metadata()).then_some(Chunk::Metadata);
+        let supercompression_global_data = (self.header.index.sgd_byte_length > 0
+            && self.supercompression_global_data.is_none())
+        .then_some(Chunk::SupercompressionGlobalData);
+        let levels = (0..self.levels.len())
+            .rev()
+            .filter(move |&level| self.levels[level].is_none())
+            .map(|level| Chunk::Level(level as u32));
+        metadata
+            .into_iter()
+            .chain(supercompression_global_data)
+            .chain(levels)
+            .filter_map(move |chunk| {
+                Some(RangeRequest {
+                    chunk,
+                    range: self.range(chunk)?,
+                })
+            })
+    }
+
+    /// The first of the [`requests`](Self::requests), or `None` once every chunk has been pushed
+    pub fn next_request(&self) -> Option<RangeRequest> {
+        self.requests().next()
+    }
+
+    /// Supply the bytes of `chunk`, which must span its whole [`range`](Self::range)
+    pub fn push(&mut self, chunk: Chunk, data: &[u8]) -> Result<(), ChunkError> {
+        let range = self.range(chunk).ok_or(ChunkError::Unexpected)?;
+        let expected = range.end - range.start;
+        if data.len() as u64 != expected {
+            return Err(ChunkError::LengthMismatch { expected });
+        }
+        match chunk {
+            Chunk::Metadata => {
+                if self.has_metadata() {
+                    return Err(ChunkError::Unexpected);
+                }
+                let mut metadata = self.metadata.clone();
+                metadata.extend_from_slice(data);
+                let level_index = Probe::new(&metadata)?.level_index().collect::<Vec<_>>();
+                if level_index
+                    .iter()
+                    .any(|level| level.byte_offset.checked_add(level.byte_length).is_none())
+                {
+                    return Err(ParseError::UnexpectedEnd.into());
+                }
+                self.levels = vec![None; level_index.len()];
+                self.level_index = level_index;
+                self.metadata = metadata;
+            }
+            Chunk::SupercompressionGlobalData => {
+                if self.supercompression_global_data.is_some() {
+                    return Err(ChunkError::Unexpected);
+                }
+                self.supercompression_global_data = Some(data.to_vec());
+            }
+            Chunk::Level(level) => {
+                let slot = &mut self.levels[level as usize];
+                if slot.is_some() {
+                    return Err(ChunkError::Unexpected);
+                }
+                *slot = Some(data.to_vec());
+            }
+        }
+        Ok(())
+    }
+
+    /// Level index, data format descriptor and key/value data, once the metadata has been pushed
+    pub fn probe(&self) -> Option<Probe<'_>> {
+        if !self.has_metadata() {
+            return None;
+        }
+        Some(Probe::new(&self.metadata).unwrap())
+    }
+
+    /// Supercompression global data, once it has been pushed
+    pub fn supercompression_global_data(&self) -> Option<&[u8]> {
+        self.supercompression_global_data.as_deref()
+    }
+
+    /// A level's data, once it has been pushed
+    pub fn level(&self, level: u32) -> Option<Level<'_>> {
+        let data = self.levels.get(level as usize)?.as_deref()?;
+        Some(Level {
+            data,
+            uncompressed_byte_length: self.level_index[level as usize].uncompressed_byte_length,
+        })
+    }
+
+    /// Whether every chunk has been pushed
+    pub fn is_complete(&self) -> bool {
+        self.next_request().is_none()
+    }
+
+    fn has_metadata(&self) -> bool {
+        self.metadata.len() > Header::LENGTH
+    }
+}
+
+#[test]
+fn test_progressive_reader_from_in_memory_server() {
+    use crate::Reader;
+    use core::convert::TryInto;
+
+    let file = crate::test_util::TEST_TEX;
+    let fetch = |range: Range<u64>| &file[range.start as usize..range.end as usize];
+    let expected = Reader::new(file).unwrap();
+
+    let mut reader = ProgressiveReader::new(fetch(0..Header::LENGTH as u64).try_into().unwrap()).unwrap();
+    assert_eq!(reader.header(), expected.header());
+    assert!(reader.probe().is_none());
+    assert!(matches!(reader.push(Chunk::Level(0), &[]), Err(ChunkError::Unexpected)));
+    assert!(matches!(
+        reader.push(Chunk::Metadata, &[]),
+        Err(ChunkError::LengthMismatch { .. })
+    ));
+
+    let mut chunks = Vec::new();
+    while let Some(request) = reader.next_request() {
+        reader.push(request.chunk, fetch(request.range)).unwrap();
+        chunks.push(request.chunk);
+    }
+    assert!(reader.is_complete());
+    assert_eq!(chunks[..3], [Chunk::Metadata, Chunk::Level(10), Chunk::Level(9)]);
+    assert_eq!(chunks.len(), 12);
+
+    assert!(reader
+        .probe()
+        .unwrap()
+        .key_value_data()
+        .unwrap()
+        .eq(expected.key_value_data()));
+    for (level, expected) in expected.levels().enumerate() {
+        assert_eq!(reader.level(level as u32).unwrap().data, expected.data);
+    }
+    assert!(matches!(
+        reader.push(Chunk::Level(0), fetch(reader.range(Chunk::Level(0)).unwrap())),
+        Err(ChunkError::Unexpected)
+    ));
+}
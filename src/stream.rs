@@ -0,0 +1,354 @@
+use crate::{
+    check_bounds, error::StreamError, image::image_layout, DataFormatDescriptor, DataFormatDescriptorIterator, Header,
+    LevelIndex,
+};
+use alloc::{vec, vec::Vec};
+use core::convert::TryInto;
+use std::io::{Read, Seek, SeekFrom};
+
+/// Header, level index, data format descriptor and key/value data of a texture being streamed.
+///
+/// Shared by the blocking and async stream readers, which only differ in how bytes are fetched.
+pub(crate) struct Sections {
+    pub(crate) header: Header,
+    pub(crate) level_index: Vec<LevelIndex>,
+    pub(crate) dfd: Vec<u8>,
+    pub(crate) key_value_data: Vec<u8>,
+}
+
+impl Sections {
+    /// Check that a stream of `length` bytes can hold a header.
+    pub(crate) fn check_length(length: u64) -> Result<(), StreamError> {
+        if length < Header::LENGTH as u64 {
+            return Err(crate::ParseError::UnexpectedEnd.into());
+        }
+        Ok(())
+    }
+
+    /// Parse the header and return the length of the level index following it.
+    pub(crate) fn parse_header(data: &[u8; Header::LENGTH], length: u64) -> Result<(Header, usize), StreamError> {
+        let header = Header::from_bytes(data)?;
+        let level_index_length = header.level_count.max(1) as u64 * LevelIndex::LENGTH as u64;
+        if Header::LENGTH as u64 + level_index_length > length {
+            return Err(crate::ParseError::UnexpectedEnd.into());
+        }
+        Ok((header, level_index_length as usize))
+    }
+
+    /// Parse the level index and check all sections against the stream length. The data format descriptor and
+    /// key/value data are zeroed and must be filled in by the reader.
+    pub(crate) fn new(header: Header, level_index_data: &[u8], length: u64) -> Result<Self, StreamError> {
+        let level_index = level_index_data
+            .chunks_exact(LevelIndex::LENGTH)
+            .map(|data| LevelIndex::from_bytes(data.try_into().unwrap()))
+            .collect::<Vec<_>>();
+        check_bounds(&header, level_index.iter().copied(), length)?;
+        Ok(Self {
+            header,
+            level_index,
+            dfd: vec![0; header.index.dfd_byte_length as usize],
+            key_value_data: vec![0; header.index.kvd_byte_length as usize],
+        })
+    }
+
+    pub(crate) fn data_format_descriptors(&self) -> impl Iterator<Item = DataFormatDescriptor<'_>> {
+        DataFormatDescriptorIterator {
+            // Skip the data format descriptors total length
+            data: &self.dfd[4..],
+        }
+    }
+
+    /// Offset and length of the supercompression global data
+    pub(crate) fn supercompression_global_data_range(&self) -> (u64, u64) {
+        (self.header.index.sgd_byte_offset, self.header.index.sgd_byte_length)
+    }
+
+    /// Offset and length of a level's data
+    pub(crate) fn level_range(&self, level: u32) -> Result<(u64, u64), StreamError> {
+        let index = self.level_index.get(level as usize).ok_or(StreamError::OutOfRange)?;
+        Ok((index.byte_offset, index.byte_length))
+    }
+
+    /// Offset and length of a single image of an uncompressed level
+    pub(crate) fn image_range(
+        &self,
+        level: u32,
+        layer: u32,
+        face: u32,
+        z_slice: u32,
+    ) -> Result<(u64, u64), StreamError> {
+        if self.header.supercompression_scheme.is_some() {
+            return Err(StreamError::Supercompressed);
+        }
+        let (offset, length) = self.level_range(level)?;
+        let layout =
+            image_layout(&self.header, self.data_format_descriptors(), level).ok_or(StreamError::OutOfRange)?;
+        let image = layout
+            .image_index(layer, face, z_slice)
+            .ok_or(StreamError::OutOfRange)?;
+        let byte_length = layout.byte_length as u64;
+        let start = image
+            .checked_mul(byte_length)
+            .filter(|start| start.checked_add(byte_length).is_some_and(|end| end <= length))
+            .ok_or(crate::ParseError::UnexpectedEnd)?;
+        Ok((offset + start, byte_length))
+    }
+}
+
+/// Check that `buf` can hold exactly `length` bytes.
+pub(crate) fn check_buffer(buf: &[u8], length: u64) -> Result<(), StreamError> {
+    if buf.len() as u64 != length {
+        return Err(StreamError::BufferLengthMismatch { expected: length });
+    }
+    Ok(())
+}
+
+/// Accessors shared by every stream reader, which store their [`Sections`] in `sections` and their stream in `reader`.
+macro_rules! stream_accessors {
+    () => {
+        /// Container-level metadata
+        pub fn header(&self) -> crate::Header {
+            self.sections.header
+        }
+
+        /// Location of each level in the stream, starting with the base level
+        pub fn level_index(&self) -> &[crate::LevelIndex] {
+            &self.sections.level_index
+        }
+
+        pub fn data_format_descriptors(&self) -> impl Iterator<Item = crate::DataFormatDescriptor<'_>> {
+            self.sections.data_format_descriptors()
+        }
+
+        /// Iterator over the key-value pairs
+        pub fn key_value_data(&self) -> crate::KeyValueDataIterator<'_> {
+            crate::KeyValueDataIterator::new(&self.sections.key_value_data)
+        }
+
+        /// Unwrap the underlying stream
+        pub fn into_inner(self) -> R {
+            self.reader
+        }
+    };
+}
+
+/// Async stream reader over a stream trait, fetching bytes with `stream_length` and `read_exact_at` from the
+/// invoking module.
+#[cfg(any(feature = "futures-io", feature = "tokio"))]
+macro_rules! async_stream_reader {
+    ($(#[$attr:meta])* $name:ident: $($bound:tt)+) => {
+        $(#[$attr])*
+        pub struct $name<R> {
+            reader: R,
+            sections: crate::stream::Sections,
+        }
+
+        impl<R> $name<R> {
+            stream_accessors!();
+        }
+
+        impl<R: $($bound)+> $name<R> {
+            /// Read the header, level index, data format descriptor and key/value data from `reader`
+            pub async fn new(mut reader: R) -> Result<Self, crate::StreamError> {
+                let length = stream_length(&mut reader).await?;
+                crate::stream::Sections::check_length(length)?;
+                let mut header_data = [0; crate::Header::LENGTH];
+                read_exact_at(&mut reader, 0, &mut header_data).await?;
+                let (header, level_index_length) = crate::stream::Sections::parse_header(&header_data, length)?;
+                let mut level_index_data = alloc::vec![0; level_index_length];
+                read_exact_at(&mut reader, crate::Header::LENGTH as u64, &mut level_index_data).await?;
+                let mut sections = crate::stream::Sections::new(header, &level_index_data, length)?;
+                read_exact_at(&mut reader, header.index.dfd_byte_offset as u64, &mut sections.dfd).await?;
+                read_exact_at(&mut reader, header.index.kvd_byte_offset as u64, &mut sections.key_value_data).await?;
+                Ok(Self { reader, sections })
+            }
+
+            /// Read the supercompression global data into `buf`, whose length must be [`Index::sgd_byte_length`](crate::Index::sgd_byte_length).
+            pub async fn read_supercompression_global_data(&mut self, buf: &mut [u8]) -> Result<(), crate::StreamError> {
+                let range = self.sections.supercompression_global_data_range();
+                self.read_range(range, buf).await
+            }
+
+            /// Read a level's data, which is supercompressed if the texture is, into `buf`. Its length must be the
+            /// level's [`LevelIndex::byte_length`](crate::LevelIndex::byte_length).
+            pub async fn read_level(&mut self, level: u32, buf: &mut [u8]) -> Result<(), crate::StreamError> {
+                let range = self.sections.level_range(level)?;
+                self.read_range(range, buf).await
+            }
+
+            /// Read a single image of an uncompressed level into `buf`, whose length must be the image's length.
+            ///
+            /// `layer` and `z_slice` are 0 for textures without layers or depth.
+            pub async fn read_image(
+                &mut self,
+                level: u32,
+                layer: u32,
+                face: u32,
+                z_slice: u32,
+                buf: &mut [u8],
+            ) -> Result<(), crate::StreamError> {
+                let range = self.sections.image_range(level, layer, face, z_slice)?;
+                self.read_range(range, buf).await
+            }
+
+            async fn read_range(&mut self, (offset, length): (u64, u64), buf: &mut [u8]) -> Result<(), crate::StreamError> {
+                crate::stream::check_buffer(buf, length)?;
+                read_exact_at(&mut self.reader, offset, buf).await?;
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Future checking an async stream reader over `test_tex.ktx2` against [`Reader`](crate::Reader), with `$cursor`
+/// wrapping byte slices in the reader's stream trait.
+#[cfg(all(test, any(feature = "futures-io", feature = "tokio")))]
+macro_rules! test_async_stream_reader {
+    ($name:ident, $cursor:ident) => {
+        async {
+            use crate::{test_util::TEST_TEX, Reader, StreamError};
+            use alloc::{vec, vec::Vec};
+
+            let reader = Reader::new(TEST_TEX).unwrap();
+            let mut stream = $name::new($cursor::new(TEST_TEX)).await.unwrap();
+            assert_eq!(stream.header(), reader.header());
+            assert!(stream.key_value_data().eq(reader.key_value_data()));
+
+            // Coarsest level first
+            for (level, expected) in reader.levels().enumerate().collect::<Vec<_>>().into_iter().rev() {
+                let mut data = vec![0; expected.data.len()];
+                stream.read_level(level as u32, &mut data).await.unwrap();
+                assert_eq!(data, expected.data);
+            }
+
+            let mut image = vec![0; 8 * 4 * 4];
+            stream.read_image(7, 0, 0, 0, &mut image).await.unwrap();
+            assert_eq!(&image[..], reader.image(7, 0, 0, 0).unwrap());
+            assert!(matches!(
+                stream.read_level(11, &mut []).await,
+                Err(StreamError::OutOfRange)
+            ));
+            assert!(matches!(
+                $name::new($cursor::new(&TEST_TEX[..1000])).await,
+                Err(StreamError::Parse(crate::ParseError::UnexpectedEnd))
+            ));
+        }
+    };
+}
+
+/// Decodes KTX2 texture data from a seekable stream, reading levels on demand.
+///
+/// The header, level index, data format descriptor and key/value data are read and checked up front. Level data
+/// and supercompression global data are read into caller-provided buffers.
+///
+/// ```rust
+/// # let file = include_bytes!("../data/test_tex.ktx2");
+/// let mut reader = ktx2::StreamReader::new(std::io::Cursor::new(&file[..])).unwrap();
+/// let mut level = vec![0; reader.level_index()[10].byte_length as usize];
+/// reader.read_level(10, &mut level).unwrap();
+/// # assert_eq!(level.len(), 4);
+/// ```
+pub struct StreamReader<R> {
+    reader: R,
+    sections: Sections,
+}
+
+impl<R> StreamReader<R> {
+    stream_accessors!();
+}
+
+impl<R: Read + Seek> StreamReader<R> {
+    /// Read the header, level index, data format descriptor and key/value data from `reader`
+    pub fn new(mut reader: R) -> Result<Self, StreamError> {
+        let length = reader.seek(SeekFrom::End(0))?;
+        Sections::check_length(length)?;
+        let mut header_data = [0; Header::LENGTH];
+        read_exact_at(&mut reader, 0, &mut header_data)?;
+        let (header, level_index_length) = Sections::parse_header(&header_data, length)?;
+        let mut level_index_data = vec![0; level_index_length];
+        read_exact_at(&mut reader, Header::LENGTH as u64, &mut level_index_data)?;
+        let mut sections = Sections::new(header, &level_index_data, length)?;
+        read_exact_at(&mut reader, header.index.dfd_byte_offset as u64, &mut sections.dfd)?;
+        read_exact_at(
+            &mut reader,
+            header.index.kvd_byte_offset as u64,
+            &mut sections.key_value_data,
+        )?;
+        Ok(Self { reader, sections })
+    }
+
+    /// Read the supercompression global data into `buf`, whose length must be [`Index::sgd_byte_length`](crate::Index::sgd_byte_length).
+    pub fn read_supercompression_global_data(&mut self, buf: &mut [u8]) -> Result<(), StreamError> {
+        let range = self.sections.supercompression_global_data_range();
+        self.read_range(range, buf)
+    }
+
+    /// Read a level's data, which is supercompressed if the texture is, into `buf`. Its length must be the
+    /// level's [`LevelIndex::byte_length`].
+    pub fn read_level(&mut self, level: u32, buf: &mut [u8]) -> Result<(), StreamError> {
+        let range = self.sections.level_range(level)?;
+        self.read_range(range, buf)
+    }
+
+    /// Read a single image of an uncompressed level into `buf`, whose length must be the image's length.
+    ///
+    /// `layer` and `z_slice` are 0 for textures without layers or depth.
+    pub fn read_image(
+        &mut self,
+        level: u32,
+        layer: u32,
+        face: u32,
+        z_slice: u32,
+        buf: &mut [u8],
+    ) -> Result<(), StreamError> {
+        let range = self.sections.image_range(level, layer, face, z_slice)?;
+        self.read_range(range, buf)
+    }
+
+    fn read_range(&mut self, (offset, length): (u64, u64), buf: &mut [u8]) -> Result<(), StreamError> {
+        check_buffer(buf, length)?;
+        read_exact_at(&mut self.reader, offset, buf)?;
+        Ok(())
+    }
+}
+
+fn read_exact_at(reader: &mut (impl Read + Seek), offset: u64, buf: &mut [u8]) -> std::io::Result<()> {
+    reader.seek(SeekFrom::Start(offset))?;
+    reader.read_exact(buf)
+}
+
+#[test]
+fn test_stream_reader_matches_reader() {
+    use crate::Reader;
+    use std::io::Cursor;
+
+    let file = crate::test_util::TEST_TEX;
+    let reader = Reader::new(file).unwrap();
+    let mut stream = StreamReader::new(Cursor::new(file)).unwrap();
+
+    assert_eq!(stream.header(), reader.header());
+    assert!(stream
+        .data_format_descriptors()
+        .map(|dfd| (dfd.header, dfd.data))
+        .eq(reader.data_format_descriptors().map(|dfd| (dfd.header, dfd.data))));
+    assert!(stream.key_value_data().eq(reader.key_value_data()));
+    for (level, expected) in reader.levels().enumerate() {
+        let mut data = vec![0; expected.data.len()];
+        stream.read_level(level as u32, &mut data).unwrap();
+        assert_eq!(data, expected.data);
+    }
+
+    let mut image = vec![0; 8 * 4 * 4];
+    stream.read_image(7, 0, 0, 0, &mut image).unwrap();
+    assert_eq!(&image[..], reader.image(7, 0, 0, 0).unwrap());
+
+    assert!(matches!(
+        stream.read_level(0, &mut [0; 4]),
+        Err(StreamError::BufferLengthMismatch { expected: 2097152 })
+    ));
+    assert!(matches!(stream.read_level(11, &mut []), Err(StreamError::OutOfRange)));
+    assert!(matches!(
+        StreamReader::new(Cursor::new(&file[..1000])),
+        Err(StreamError::Parse(crate::ParseError::UnexpectedEnd))
+    ));
+}
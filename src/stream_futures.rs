@@ -0,0 +1,39 @@
+use core::{future::poll_fn, pin::Pin};
+use futures_io::{AsyncRead, AsyncSeek};
+use std::io::{self, SeekFrom};
+
+async_stream_reader! {
+    /// Decodes KTX2 texture data from a [`futures_io`] stream, reading levels on demand.
+    ///
+    /// The header, level index, data format descriptor and key/value data are fetched with a few small reads up
+    /// front. Levels, single images and supercompression global data are read lazily into caller-provided buffers,
+    /// so coarse mips can be loaded without waiting for the whole file.
+    FuturesStreamReader: AsyncRead + AsyncSeek + Unpin
+}
+
+async fn stream_length(reader: &mut (impl AsyncSeek + Unpin)) -> io::Result<u64> {
+    poll_fn(|cx| Pin::new(&mut *reader).poll_seek(cx, SeekFrom::End(0))).await
+}
+
+async fn read_exact_at(
+    reader: &mut (impl AsyncRead + AsyncSeek + Unpin),
+    offset: u64,
+    buf: &mut [u8],
+) -> io::Result<()> {
+    poll_fn(|cx| Pin::new(&mut *reader).poll_seek(cx, SeekFrom::Start(offset))).await?;
+    let mut filled = 0;
+    while filled < buf.len() {
+        match poll_fn(|cx| Pin::new(&mut *reader).poll_read(cx, &mut buf[filled..])).await? {
+            0 => return Err(io::ErrorKind::UnexpectedEof.into()),
+            read => filled += read,
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_futures_stream_reader_matches_reader() {
+    use futures::{executor::block_on, io::Cursor};
+
+    block_on(test_async_stream_reader!(FuturesStreamReader, Cursor));
+}
@@ -0,0 +1,33 @@
+use std::io::{self, SeekFrom};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+async_stream_reader! {
+    /// Decodes KTX2 texture data from a [`tokio`] stream, reading levels on demand.
+    ///
+    /// The header, level index, data format descriptor and key/value data are fetched with a few small reads up
+    /// front. Levels, single images and supercompression global data are read lazily into caller-provided buffers,
+    /// so coarse mips can be loaded without waiting for the whole file.
+    TokioStreamReader: AsyncRead + AsyncSeek + Unpin
+}
+
+async fn stream_length(reader: &mut (impl AsyncSeek + Unpin)) -> io::Result<u64> {
+    reader.seek(SeekFrom::End(0)).await
+}
+
+async fn read_exact_at(
+    reader: &mut (impl AsyncRead + AsyncSeek + Unpin),
+    offset: u64,
+    buf: &mut [u8],
+) -> io::Result<()> {
+    reader.seek(SeekFrom::Start(offset)).await?;
+    reader.read_exact(buf).await?;
+    Ok(())
+}
+
+#[test]
+fn test_tokio_stream_reader_matches_reader() {
+    use std::io::Cursor;
+
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    runtime.block_on(test_async_stream_reader!(TokioStreamReader, Cursor));
+}
@@ -0,0 +1,161 @@
+use crate::{error::DecompressionError, Level, Reader, SupercompressionScheme};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+impl Level<'_> {
+    /// Decompress the level's data according to `scheme`, which is normally the header's supercompression scheme.
+    ///
+    /// The result is checked against [`Level::uncompressed_byte_length`]. Without supercompression the data is
+    /// copied as-is.
+    pub fn decompress(&self, scheme: Option<SupercompressionScheme>) -> Result<Vec<u8>, DecompressionError> {
+        let length = usize::try_from(self.uncompressed_byte_length).map_err(|_| DecompressionError::LengthMismatch)?;
+        let data = match scheme {
+            None => self.data.to_vec(),
+            #[cfg(feature = "zstd")]
+            Some(SupercompressionScheme::Zstandard) => zstd_decompress(self.data, length)?,
+            #[cfg(feature = "zlib")]
+            Some(SupercompressionScheme::ZLIB) => zlib_decompress(self.data, length)?,
+            Some(scheme) => return Err(DecompressionError::UnsupportedScheme(scheme)),
+        };
+        if data.len() != length {
+            return Err(DecompressionError::LengthMismatch);
+        }
+        Ok(data)
+    }
+}
+
+impl<Data: AsRef<[u8]>> Reader<Data> {
+    /// Iterator over the texture's mip levels, decompressed according to the header's supercompression scheme.
+    pub fn decoded_levels(&self) -> impl ExactSizeIterator<Item = Result<Vec<u8>, DecompressionError>> + '_ {
+        let scheme = self.header().supercompression_scheme;
+        self.levels().map(move |level| level.decompress(scheme))
+    }
+}
+
+/// Decompress every frame in `data`, failing as soon as the output exceeds `length`.
+///
+/// The output grows with the decoded data instead of being allocated up front, so a bogus `length` can't cause a
+/// large allocation.
+#[cfg(feature = "zstd")]
+fn zstd_decompress(mut data: &[u8], length: usize) -> Result<Vec<u8>, DecompressionError> {
+    use ruzstd::decoding::{
+        errors::{FrameDecoderError, ReadFrameHeaderError},
+        BlockDecodingStrategy, FrameDecoder,
+    };
+
+    let mut decoder = FrameDecoder::new();
+    let mut output = Vec::new();
+    while !data.is_empty() {
+        match decoder.reset(&mut data) {
+            Ok(()) => {}
+            Err(FrameDecoderError::ReadFrameHeaderError(ReadFrameHeaderError::SkipFrame { length, .. })) => {
+                data = data.get(length as usize..).ok_or(DecompressionError::InvalidData)?;
+                continue;
+            }
+            Err(_) => return Err(DecompressionError::InvalidData),
+        }
+        loop {
+            let finished = decoder
+                .decode_blocks(&mut data, BlockDecodingStrategy::UptoBytes(64 * 1024))
+                .map_err(|_| DecompressionError::InvalidData)?;
+            let decoded = decoder.collect().unwrap_or_default();
+            if decoded.len() > length - output.len() {
+                return Err(DecompressionError::LengthMismatch);
+            }
+            output.extend_from_slice(&decoded);
+            if finished {
+                break;
+            }
+        }
+    }
+    Ok(output)
+}
+
+#[cfg(feature = "zlib")]
+fn zlib_decompress(data: &[u8], length: usize) -> Result<Vec<u8>, DecompressionError> {
+    use miniz_oxide::inflate::{decompress_to_vec_zlib_with_limit, TINFLStatus};
+
+    decompress_to_vec_zlib_with_limit(data, length).map_err(|error| match error.status {
+        TINFLStatus::HasMoreOutput => DecompressionError::LengthMismatch,
+        _ => DecompressionError::InvalidData,
+    })
+}
+
+/// Compress uncompressed level data with `scheme` for the [`Writer`](crate::Writer).
+#[cfg(feature = "zlib")]
+pub(crate) fn compress(data: &[u8], scheme: SupercompressionScheme) -> Option<Vec<u8>> {
+    match scheme {
+        SupercompressionScheme::ZLIB => Some(miniz_oxide::deflate::compress_to_vec_zlib(data, 6)),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn test_zstd_decompress() {
+    use ruzstd::encoding::{compress_to_vec, CompressionLevel};
+
+    let uncompressed = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+    let compressed = compress_to_vec(&uncompressed[..], CompressionLevel::Fastest);
+    let scheme = Some(SupercompressionScheme::Zstandard);
+
+    let level = Level {
+        data: &compressed,
+        uncompressed_byte_length: uncompressed.len() as u64,
+    };
+    assert_eq!(level.decompress(scheme).unwrap(), uncompressed);
+
+    for uncompressed_byte_length in [999, 1001] {
+        let level = Level {
+            data: &compressed,
+            uncompressed_byte_length,
+        };
+        assert_eq!(level.decompress(scheme), Err(DecompressionError::LengthMismatch));
+    }
+
+    let level = Level {
+        data: &compressed[1..],
+        uncompressed_byte_length: 1000,
+    };
+    assert_eq!(level.decompress(scheme), Err(DecompressionError::InvalidData));
+
+    // A claimed length far beyond the data is only reached by decoding, not by allocating
+    let level = Level {
+        data: &compressed,
+        uncompressed_byte_length: u32::MAX as u64 * 16,
+    };
+    assert_eq!(level.decompress(scheme), Err(DecompressionError::LengthMismatch));
+}
+
+#[cfg(feature = "zlib")]
+#[test]
+fn test_zlib_decompress() {
+    let uncompressed = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+    let compressed = compress(&uncompressed, SupercompressionScheme::ZLIB).unwrap();
+    let scheme = Some(SupercompressionScheme::ZLIB);
+
+    let level = Level {
+        data: &compressed,
+        uncompressed_byte_length: uncompressed.len() as u64,
+    };
+    assert_eq!(level.decompress(scheme).unwrap(), uncompressed);
+
+    for uncompressed_byte_length in [999, 1001] {
+        let level = Level {
+            data: &compressed,
+            uncompressed_byte_length,
+        };
+        assert_eq!(level.decompress(scheme), Err(DecompressionError::LengthMismatch));
+    }
+
+    // Corrupt header, then a truncated stream
+    let mut corrupt = compressed.clone();
+    corrupt[0] ^= 0xFF;
+    for data in [&corrupt[..], &compressed[..compressed.len() / 2]] {
+        let level = Level {
+            data,
+            uncompressed_byte_length: 1000,
+        };
+        assert_eq!(level.decompress(scheme), Err(DecompressionError::InvalidData));
+    }
+}
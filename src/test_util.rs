@@ -0,0 +1,41 @@
+//! Fixtures shared by the unit tests.
+
+#[cfg(feature = "alloc")]
+use crate::{Format, Level, Reader, Writer, WriterHeader};
+
+/// `data/test_tex.ktx2`, a 1024x512 `R8G8B8A8_UINT` texture with 11 levels.
+pub(crate) const TEST_TEX: &[u8] = include_bytes!("../data/test_tex.ktx2");
+
+/// The data format descriptor blocks of [`TEST_TEX`], without the leading `dfdTotalSize`.
+#[cfg(feature = "alloc")]
+pub(crate) fn test_tex_dfd() -> &'static [u8] {
+    let index = Reader::new(TEST_TEX).unwrap().header().index;
+    &TEST_TEX[index.dfd_byte_offset as usize + 4..(index.dfd_byte_offset + index.dfd_byte_length) as usize]
+}
+
+/// Header of a square 2D texture with a single uncompressed level.
+#[cfg(feature = "alloc")]
+pub(crate) fn writer_header(format: Option<Format>, size: u32) -> WriterHeader {
+    WriterHeader {
+        format,
+        type_size: 1,
+        pixel_width: size,
+        pixel_height: size,
+        pixel_depth: 0,
+        layer_count: 0,
+        face_count: 1,
+        level_count: 1,
+        supercompression_scheme: None,
+    }
+}
+
+/// Writer of a square 2D texture whose single level holds `data`.
+#[cfg(feature = "alloc")]
+pub(crate) fn single_level_writer<'a>(format: Option<Format>, size: u32, dfd: &'a [u8], data: &'a [u8]) -> Writer<'a> {
+    let mut writer = Writer::new(writer_header(format, size));
+    writer.data_format_descriptors(dfd).level(Level {
+        data,
+        uncompressed_byte_length: data.len() as u64,
+    });
+    writer
+}
@@ -0,0 +1,428 @@
+use crate::{
+    bytes_to_u32, level_alignment, metadata::is_valid_value, BasicDataFormatDescriptor, ColorModel,
+    DataFormatDescriptorHeader, DataFormatDescriptorIterator, Format, Reader, SupercompressionScheme,
+};
+use alloc::{string::String, vec::Vec};
+
+/// How severe a [`ValidationIssue`] is.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Allowed by the specification, but likely unintended
+    Warning,
+    /// Violates the specification
+    Error,
+}
+
+/// A specification violation found by [`Reader::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationIssue {
+    /// More levels than a full mip chain of the base level has
+    LevelCountTooLarge { level_count: u32, max_level_count: u32 },
+    /// Face count is neither 1 nor 6
+    InvalidFaceCount(u32),
+    /// Cubemap faces are not square
+    NonSquareCubemap { pixel_width: u32, pixel_height: u32 },
+    /// Cubemap has a pixel depth
+    CubemapWithDepth(u32),
+    /// Texture has a pixel depth, but no pixel height
+    DepthWithoutHeight,
+    /// Type size doesn't match the one required by the format
+    TypeSizeMismatch { type_size: u32, expected: u32 },
+    /// `dfdTotalSize` differs from `dfdByteLength` in the index
+    DfdTotalSizeMismatch { dfd_total_size: u32, dfd_byte_length: u32 },
+    /// Descriptor blocks don't exactly fill the data format descriptor
+    MalformedDataFormatDescriptor,
+    /// First descriptor block isn't a basic descriptor block
+    MissingBasicDataFormatDescriptor,
+    /// The basic descriptor block doesn't describe the header's format
+    FormatMismatch { format: Format },
+    /// The basic descriptor block of a supercompressed texture has non-zero `bytesPlane` fields
+    BytesPlanesNotZero,
+    /// The header's format and the basic descriptor block's transfer function disagree on sRGB encoding
+    TransferFunctionMismatch { format: Format },
+    /// A key-value pair extends past the key/value data or its length is zero
+    MalformedKeyValueData { offset: usize },
+    /// A key isn't terminated by a NUL character
+    MissingKeyTerminator { offset: usize },
+    /// A key isn't valid UTF-8
+    InvalidKeyEncoding { offset: usize },
+    /// Keys are not sorted by their byte values
+    KeyValueDataNotSorted { key: String },
+    /// A key appears more than once
+    DuplicateKey { key: String },
+    /// The recommended `KTXwriter` key is missing
+    MissingWriter,
+    /// Level data isn't aligned as required
+    MisalignedLevel {
+        level: usize,
+        byte_offset: u64,
+        alignment: u64,
+    },
+    /// Level data isn't stored smallest level first
+    LevelOrder { level: usize },
+    /// Level's uncompressed length differs from its length without supercompression
+    UncompressedLengthMismatch { level: usize },
+    /// Level's uncompressed length differs from the size implied by the header's format and dimensions
+    LevelSizeMismatch {
+        level: usize,
+        uncompressed_byte_length: u64,
+        expected: u64,
+    },
+    /// Level's size implied by the header's format and dimensions doesn't fit in 64 bits
+    LevelSizeOverflow { level: usize },
+    /// A standard key's value doesn't follow the format the specification requires
+    InvalidValue { key: String },
+}
+
+impl ValidationIssue {
+    pub fn severity(&self) -> Severity {
+        match self {
+            ValidationIssue::MissingWriter => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl<Data: AsRef<[u8]>> Reader<Data> {
+    /// Check the whole file against the KTX2 specification.
+    ///
+    /// Unlike [`Reader::new`], which only ensures the file can be read safely, this reports every
+    /// violation it finds.
+    ///
+    /// ```rust
+    /// # let file = include_bytes!("../data/test_tex.ktx2");
+    /// let reader = ktx2::Reader::new(file).unwrap();
+    /// let errors = reader
+    ///     .validate()
+    ///     .into_iter()
+    ///     .filter(|issue| issue.severity() == ktx2::Severity::Error)
+    ///     .count();
+    /// assert_eq!(errors, 0);
+    /// ```
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        self.validate_header(&mut issues);
+        self.validate_data_format_descriptor(&mut issues);
+        self.validate_key_value_data(&mut issues);
+        self.validate_levels(&mut issues);
+        issues
+    }
+
+    fn validate_header(&self, issues: &mut Vec<ValidationIssue>) {
+        let header = self.header();
+
+        let max_dimension = header.pixel_width.max(header.pixel_height).max(header.pixel_depth);
+        let max_level_count = 32 - max_dimension.leading_zeros();
+        if header.level_count > max_level_count {
+            issues.push(ValidationIssue::LevelCountTooLarge {
+                level_count: header.level_count,
+                max_level_count,
+            });
+        }
+
+        if header.face_count != 1 && header.face_count != 6 {
+            issues.push(ValidationIssue::InvalidFaceCount(header.face_count));
+        }
+        if header.face_count == 6 {
+            if header.pixel_width != header.pixel_height {
+                issues.push(ValidationIssue::NonSquareCubemap {
+                    pixel_width: header.pixel_width,
+                    pixel_height: header.pixel_height,
+                });
+            }
+            if header.pixel_depth != 0 {
+                issues.push(ValidationIssue::CubemapWithDepth(header.pixel_depth));
+            }
+        }
+        if header.pixel_depth != 0 && header.pixel_height == 0 {
+            issues.push(ValidationIssue::DepthWithoutHeight);
+        }
+
+        let expected = header.format.map_or(1, expected_type_size);
+        if header.type_size != expected {
+            issues.push(ValidationIssue::TypeSizeMismatch {
+                type_size: header.type_size,
+                expected,
+            });
+        }
+    }
+
+    fn validate_data_format_descriptor(&self, issues: &mut Vec<ValidationIssue>) {
+        let header = self.header();
+        let dfd = self.dfd_bytes();
+
+        let dfd_total_size = u32::from_le_bytes([dfd[0], dfd[1], dfd[2], dfd[3]]);
+        if dfd_total_size != header.index.dfd_byte_length {
+            issues.push(ValidationIssue::DfdTotalSizeMismatch {
+                dfd_total_size,
+                dfd_byte_length: header.index.dfd_byte_length,
+            });
+        }
+
+        let blocks = &dfd[4..];
+        let mut length = 0;
+        let mut first = None;
+        for block in (DataFormatDescriptorIterator { data: blocks }) {
+            length += DataFormatDescriptorHeader::LENGTH + block.data.len();
+            first.get_or_insert(block);
+        }
+        if length != blocks.len() {
+            issues.push(ValidationIssue::MalformedDataFormatDescriptor);
+        }
+
+        let basic = first
+            .filter(|block| block.header == DataFormatDescriptorHeader::BASIC)
+            .and_then(|block| BasicDataFormatDescriptor::parse(block.data).ok());
+        let basic = match basic {
+            Some(basic) => basic,
+            None => {
+                issues.push(ValidationIssue::MissingBasicDataFormatDescriptor);
+                return;
+            }
+        };
+
+        if header.supercompression_scheme.is_some() && basic.header.bytes_planes != [0; 8] {
+            issues.push(ValidationIssue::BytesPlanesNotZero);
+        }
+
+        // ETC1S and UASTC data require an undefined format
+        let defined_by_dfd = matches!(
+            basic.header.color_model,
+            Some(ColorModel::ETC1S) | Some(ColorModel::UASTC)
+        ) || header.supercompression_scheme == Some(SupercompressionScheme::BasisLZ);
+        if let Some(format) = header.format {
+            match basic.format() {
+                Ok(dfd_format) if !defined_by_dfd && dfd_format == format => {}
+                Ok(dfd_format)
+                    if !defined_by_dfd
+                        && dfd_format.to_linear().is_some()
+                        && dfd_format.to_linear() == format.to_linear() =>
+                {
+                    issues.push(ValidationIssue::TransferFunctionMismatch { format });
+                }
+                _ => issues.push(ValidationIssue::FormatMismatch { format }),
+            }
+        }
+    }
+
+    fn validate_key_value_data(&self, issues: &mut Vec<ValidationIssue>) {
+        let header = self.header();
+        let index = header.index;
+        let start = index.kvd_byte_offset as usize;
+        let data = &self.data()[start..start + index.kvd_byte_length as usize];
+
+        let mut offset = 0;
+        let mut previous: Option<&[u8]> = None;
+        let mut has_writer = false;
+        while offset < data.len() {
+            let entry_offset = start + offset;
+            let length = match bytes_to_u32(data, &mut offset) {
+                Ok(length) if length != 0 => length as usize,
+                _ => {
+                    issues.push(ValidationIssue::MalformedKeyValueData { offset: entry_offset });
+                    break;
+                }
+            };
+            let key_and_value = match offset.checked_add(length).and_then(|end| data.get(offset..end)) {
+                Some(key_and_value) => key_and_value,
+                None => {
+                    issues.push(ValidationIssue::MalformedKeyValueData { offset: entry_offset });
+                    break;
+                }
+            };
+            offset += length;
+            offset += (4 - offset % 4) % 4;
+
+            let key = match key_and_value.iter().position(|&c| c == b'\0') {
+                Some(end) => &key_and_value[..end],
+                None => {
+                    issues.push(ValidationIssue::MissingKeyTerminator { offset: entry_offset });
+                    continue;
+                }
+            };
+            let key_str = match core::str::from_utf8(key) {
+                Ok(key) => key,
+                Err(_) => {
+                    issues.push(ValidationIssue::InvalidKeyEncoding { offset: entry_offset });
+                    continue;
+                }
+            };
+            has_writer |= key_str == "KTXwriter";
+            if !is_valid_value(key_str, &key_and_value[key.len() + 1..], &header) {
+                issues.push(ValidationIssue::InvalidValue { key: key_str.into() });
+            }
+
+            match previous {
+                Some(previous) if previous == key => {
+                    issues.push(ValidationIssue::DuplicateKey { key: key_str.into() });
+                }
+                Some(previous) if previous > key => {
+                    issues.push(ValidationIssue::KeyValueDataNotSorted { key: key_str.into() });
+                }
+                _ => {}
+            }
+            previous = Some(key);
+        }
+
+        if !has_writer {
+            issues.push(ValidationIssue::MissingWriter);
+        }
+    }
+
+    fn validate_levels(&self, issues: &mut Vec<ValidationIssue>) {
+        let header = self.header();
+        let alignment = level_alignment(header.supercompression_scheme, &self.dfd_bytes()[4..]) as u64;
+
+        // Bounds-checking previously performed in `new`
+        let index = self.level_index().unwrap().collect::<Vec<_>>();
+        for (level, entry) in index.iter().enumerate() {
+            if entry.byte_offset % alignment != 0 {
+                issues.push(ValidationIssue::MisalignedLevel {
+                    level,
+                    byte_offset: entry.byte_offset,
+                    alignment,
+                });
+            }
+            if let Some(smaller) = index.get(level + 1) {
+                if smaller.byte_offset + smaller.byte_length > entry.byte_offset {
+                    issues.push(ValidationIssue::LevelOrder { level });
+                }
+            }
+            if header.supercompression_scheme.is_none() && entry.uncompressed_byte_length != entry.byte_length {
+                issues.push(ValidationIssue::UncompressedLengthMismatch { level });
+            }
+            match header.level_layout(level as u32) {
+                Some(layout) if entry.uncompressed_byte_length != layout.byte_length => {
+                    issues.push(ValidationIssue::LevelSizeMismatch {
+                        level,
+                        uncompressed_byte_length: entry.uncompressed_byte_length,
+                        expected: layout.byte_length,
+                    });
+                }
+                // The format has a known size, so the layout could only be missing due to overflow
+                None if header.format.is_some_and(|format| format.block_byte_length() != 0) => {
+                    issues.push(ValidationIssue::LevelSizeOverflow { level });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// The data format descriptor, including `dfdTotalSize`.
+    fn dfd_bytes(&self) -> &[u8] {
+        let index = self.header().index;
+        // Bounds-checking previously performed in `new`
+        &self.data()[index.dfd_byte_offset as usize..(index.dfd_byte_offset + index.dfd_byte_length) as usize]
+    }
+}
+
+/// `typeSize` required for `format`.
+fn expected_type_size(format: Format) -> u32 {
+    match format {
+        // Combined depth/stencil formats use the size of the depth component
+        Format::D16_UNORM_S8_UINT => 2,
+        Format::X8_D24_UNORM_PACK32 | Format::D24_UNORM_S8_UINT | Format::D32_SFLOAT_S8_UINT => 4,
+        _ => {
+            let descriptor = match crate::BasicDataFormatDescriptorBuilder::from_format(format).map(|b| b.build()) {
+                Some(Ok(descriptor)) => descriptor,
+                _ => return 1,
+            };
+            let dfd = match BasicDataFormatDescriptor::parse(&descriptor[DataFormatDescriptorHeader::LENGTH..]) {
+                Ok(dfd) => dfd,
+                Err(_) => return 1,
+            };
+            if dfd.header.color_model != Some(ColorModel::RGBSDA) {
+                // Block compressed
+                return 1;
+            }
+            let first = dfd.sample_information().next().map_or(8, |sample| sample.bit_length);
+            let unpacked = dfd
+                .sample_information()
+                .all(|sample| sample.bit_length == first && sample.bit_length % 8 == 0);
+            if unpacked {
+                first / 8
+            } else {
+                dfd.header.bytes_planes[0]
+            }
+        }
+    }
+}
+
+#[test]
+fn test_validate_reports_issues() {
+    use crate::test_util::{single_level_writer, TEST_TEX};
+
+    let reader = Reader::new(TEST_TEX).unwrap();
+    assert_eq!(reader.validate(), []);
+
+    let mut file = TEST_TEX.to_vec();
+    // face_count = 6 on a 1024x512 texture
+    file[36..40].copy_from_slice(&6u32.to_le_bytes());
+    // type_size = 4 for R8G8B8A8_UINT
+    file[16..20].copy_from_slice(&4u32.to_le_bytes());
+    // Swap the offsets of the two smallest levels
+    let last = 80 + 10 * 24;
+    let second_last = 80 + 9 * 24;
+    let (a, b) = (
+        file[last..last + 8].to_vec(),
+        file[second_last..second_last + 8].to_vec(),
+    );
+    file[last..last + 8].copy_from_slice(&b);
+    file[second_last..second_last + 8].copy_from_slice(&a);
+
+    let reader = Reader::new(&file[..]).unwrap();
+    let issues = reader.validate();
+    assert!(issues.contains(&ValidationIssue::NonSquareCubemap {
+        pixel_width: 1024,
+        pixel_height: 512
+    }));
+    assert!(issues.contains(&ValidationIssue::TypeSizeMismatch {
+        type_size: 4,
+        expected: 1
+    }));
+    assert!(issues.contains(&ValidationIssue::LevelOrder { level: 9 }));
+
+    // Dimensions whose level sizes overflow
+    file[20..28].copy_from_slice(&[0xFF; 8]);
+    let reader = Reader::new(&file[..]).unwrap();
+    let issues = reader.validate();
+    assert!(issues.contains(&ValidationIssue::LevelSizeOverflow { level: 0 }));
+    assert!(!issues.contains(&ValidationIssue::LevelSizeOverflow { level: 10 }));
+    assert_eq!(reader.image(0, 0, 0, 0), None);
+
+    // sRGB header with a linear data format descriptor
+    let dfd = crate::BasicDataFormatDescriptorBuilder::from_format(Format::R8G8B8A8_UNORM)
+        .unwrap()
+        .build()
+        .unwrap();
+    let bytes = single_level_writer(Some(Format::R8G8B8A8_SRGB), 1, &dfd, &[0; 4])
+        .key_value("KTXswizzle", b"rgbx\0")
+        .write()
+        .unwrap();
+    let issues = Reader::new(&bytes[..]).unwrap().validate();
+    assert!(issues.contains(&ValidationIssue::TransferFunctionMismatch {
+        format: Format::R8G8B8A8_SRGB
+    }));
+    assert!(issues.contains(&ValidationIssue::InvalidValue {
+        key: "KTXswizzle".into()
+    }));
+}
+
+#[test]
+fn test_validate_reports_malformed_descriptor_block_size() {
+    use crate::test_util::TEST_TEX;
+
+    let mut file = TEST_TEX.to_vec();
+    // descriptorBlockSize = 4, shorter than the block header
+    let block_size = Reader::new(TEST_TEX).unwrap().header().index.dfd_byte_offset as usize + 4 + 6;
+    file[block_size..block_size + 2].copy_from_slice(&4u16.to_le_bytes());
+
+    let reader = Reader::new(&file[..]).unwrap();
+    assert_eq!(reader.data_format_descriptors().count(), 0);
+    let issues = reader.validate();
+    assert!(issues.contains(&ValidationIssue::MalformedDataFormatDescriptor));
+    assert!(issues.contains(&ValidationIssue::MissingBasicDataFormatDescriptor));
+    // Without a basic descriptor block there is no image layout
+    assert_eq!(reader.images().count(), 0);
+}
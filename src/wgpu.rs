@@ -0,0 +1,247 @@
+use crate::{error::FormatConversionError, Format, Header};
+use core::convert::TryFrom;
+use wgpu_types::{AstcBlock, AstcChannel, Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages};
+
+macro_rules! formats {
+    ($($format:ident => $texture_format:ident $({ block: $block:ident, channel: $channel:ident })?,)*) => {
+        impl TryFrom<Format> for TextureFormat {
+            type Error = FormatConversionError;
+
+            fn try_from(format: Format) -> Result<Self, Self::Error> {
+                match format {
+                    $(Format::$format => Ok(TextureFormat::$texture_format $({
+                        block: AstcBlock::$block,
+                        channel: AstcChannel::$channel,
+                    })?),)*
+                    _ => Err(FormatConversionError::Unsupported),
+                }
+            }
+        }
+
+        impl TryFrom<TextureFormat> for Format {
+            type Error = FormatConversionError;
+
+            fn try_from(format: TextureFormat) -> Result<Self, Self::Error> {
+                match format {
+                    $(TextureFormat::$texture_format $({
+                        block: AstcBlock::$block,
+                        channel: AstcChannel::$channel,
+                    })? => Ok(Format::$format),)*
+                    _ => Err(FormatConversionError::Unsupported),
+                }
+            }
+        }
+    };
+}
+
+// Formats whose texel layout matches exactly. wgpu's `Depth24Plus` formats leave the depth
+// precision to the backend, so they have no KTX equivalent.
+formats! {
+    R8_UNORM => R8Unorm,
+    R8_SNORM => R8Snorm,
+    R8_UINT => R8Uint,
+    R8_SINT => R8Sint,
+    R16_UINT => R16Uint,
+    R16_SINT => R16Sint,
+    R16_UNORM => R16Unorm,
+    R16_SNORM => R16Snorm,
+    R16_SFLOAT => R16Float,
+    R8G8_UNORM => Rg8Unorm,
+    R8G8_SNORM => Rg8Snorm,
+    R8G8_UINT => Rg8Uint,
+    R8G8_SINT => Rg8Sint,
+    R32_UINT => R32Uint,
+    R32_SINT => R32Sint,
+    R32_SFLOAT => R32Float,
+    R16G16_UINT => Rg16Uint,
+    R16G16_SINT => Rg16Sint,
+    R16G16_UNORM => Rg16Unorm,
+    R16G16_SNORM => Rg16Snorm,
+    R16G16_SFLOAT => Rg16Float,
+    R8G8B8A8_UNORM => Rgba8Unorm,
+    R8G8B8A8_SRGB => Rgba8UnormSrgb,
+    R8G8B8A8_SNORM => Rgba8Snorm,
+    R8G8B8A8_UINT => Rgba8Uint,
+    R8G8B8A8_SINT => Rgba8Sint,
+    B8G8R8A8_UNORM => Bgra8Unorm,
+    B8G8R8A8_SRGB => Bgra8UnormSrgb,
+    E5B9G9R9_UFLOAT_PACK32 => Rgb9e5Ufloat,
+    A2B10G10R10_UINT_PACK32 => Rgb10a2Uint,
+    A2B10G10R10_UNORM_PACK32 => Rgb10a2Unorm,
+    B10G11R11_UFLOAT_PACK32 => Rg11b10Ufloat,
+    R64_UINT => R64Uint,
+    R32G32_UINT => Rg32Uint,
+    R32G32_SINT => Rg32Sint,
+    R32G32_SFLOAT => Rg32Float,
+    R16G16B16A16_UINT => Rgba16Uint,
+    R16G16B16A16_SINT => Rgba16Sint,
+    R16G16B16A16_UNORM => Rgba16Unorm,
+    R16G16B16A16_SNORM => Rgba16Snorm,
+    R16G16B16A16_SFLOAT => Rgba16Float,
+    R32G32B32A32_UINT => Rgba32Uint,
+    R32G32B32A32_SINT => Rgba32Sint,
+    R32G32B32A32_SFLOAT => Rgba32Float,
+    S8_UINT => Stencil8,
+    D16_UNORM => Depth16Unorm,
+    D32_SFLOAT => Depth32Float,
+    D32_SFLOAT_S8_UINT => Depth32FloatStencil8,
+    BC1_RGBA_UNORM_BLOCK => Bc1RgbaUnorm,
+    BC1_RGBA_SRGB_BLOCK => Bc1RgbaUnormSrgb,
+    BC2_UNORM_BLOCK => Bc2RgbaUnorm,
+    BC2_SRGB_BLOCK => Bc2RgbaUnormSrgb,
+    BC3_UNORM_BLOCK => Bc3RgbaUnorm,
+    BC3_SRGB_BLOCK => Bc3RgbaUnormSrgb,
+    BC4_UNORM_BLOCK => Bc4RUnorm,
+    BC4_SNORM_BLOCK => Bc4RSnorm,
+    BC5_UNORM_BLOCK => Bc5RgUnorm,
+    BC5_SNORM_BLOCK => Bc5RgSnorm,
+    BC6H_UFLOAT_BLOCK => Bc6hRgbUfloat,
+    BC6H_SFLOAT_BLOCK => Bc6hRgbFloat,
+    BC7_UNORM_BLOCK => Bc7RgbaUnorm,
+    BC7_SRGB_BLOCK => Bc7RgbaUnormSrgb,
+    ETC2_R8G8B8_UNORM_BLOCK => Etc2Rgb8Unorm,
+    ETC2_R8G8B8_SRGB_BLOCK => Etc2Rgb8UnormSrgb,
+    ETC2_R8G8B8A1_UNORM_BLOCK => Etc2Rgb8A1Unorm,
+    ETC2_R8G8B8A1_SRGB_BLOCK => Etc2Rgb8A1UnormSrgb,
+    ETC2_R8G8B8A8_UNORM_BLOCK => Etc2Rgba8Unorm,
+    ETC2_R8G8B8A8_SRGB_BLOCK => Etc2Rgba8UnormSrgb,
+    EAC_R11_UNORM_BLOCK => EacR11Unorm,
+    EAC_R11_SNORM_BLOCK => EacR11Snorm,
+    EAC_R11G11_UNORM_BLOCK => EacRg11Unorm,
+    EAC_R11G11_SNORM_BLOCK => EacRg11Snorm,
+    ASTC_4x4_UNORM_BLOCK => Astc { block: B4x4, channel: Unorm },
+    ASTC_4x4_SRGB_BLOCK => Astc { block: B4x4, channel: UnormSrgb },
+    ASTC_4x4_SFLOAT_BLOCK => Astc { block: B4x4, channel: Hdr },
+    ASTC_5x4_UNORM_BLOCK => Astc { block: B5x4, channel: Unorm },
+    ASTC_5x4_SRGB_BLOCK => Astc { block: B5x4, channel: UnormSrgb },
+    ASTC_5x4_SFLOAT_BLOCK => Astc { block: B5x4, channel: Hdr },
+    ASTC_5x5_UNORM_BLOCK => Astc { block: B5x5, channel: Unorm },
+    ASTC_5x5_SRGB_BLOCK => Astc { block: B5x5, channel: UnormSrgb },
+    ASTC_5x5_SFLOAT_BLOCK => Astc { block: B5x5, channel: Hdr },
+    ASTC_6x5_UNORM_BLOCK => Astc { block: B6x5, channel: Unorm },
+    ASTC_6x5_SRGB_BLOCK => Astc { block: B6x5, channel: UnormSrgb },
+    ASTC_6x5_SFLOAT_BLOCK => Astc { block: B6x5, channel: Hdr },
+    ASTC_6x6_UNORM_BLOCK => Astc { block: B6x6, channel: Unorm },
+    ASTC_6x6_SRGB_BLOCK => Astc { block: B6x6, channel: UnormSrgb },
+    ASTC_6x6_SFLOAT_BLOCK => Astc { block: B6x6, channel: Hdr },
+    ASTC_8x5_UNORM_BLOCK => Astc { block: B8x5, channel: Unorm },
+    ASTC_8x5_SRGB_BLOCK => Astc { block: B8x5, channel: UnormSrgb },
+    ASTC_8x5_SFLOAT_BLOCK => Astc { block: B8x5, channel: Hdr },
+    ASTC_8x6_UNORM_BLOCK => Astc { block: B8x6, channel: Unorm },
+    ASTC_8x6_SRGB_BLOCK => Astc { block: B8x6, channel: UnormSrgb },
+    ASTC_8x6_SFLOAT_BLOCK => Astc { block: B8x6, channel: Hdr },
+    ASTC_8x8_UNORM_BLOCK => Astc { block: B8x8, channel: Unorm },
+    ASTC_8x8_SRGB_BLOCK => Astc { block: B8x8, channel: UnormSrgb },
+    ASTC_8x8_SFLOAT_BLOCK => Astc { block: B8x8, channel: Hdr },
+    ASTC_10x5_UNORM_BLOCK => Astc { block: B10x5, channel: Unorm },
+    ASTC_10x5_SRGB_BLOCK => Astc { block: B10x5, channel: UnormSrgb },
+    ASTC_10x5_SFLOAT_BLOCK => Astc { block: B10x5, channel: Hdr },
+    ASTC_10x6_UNORM_BLOCK => Astc { block: B10x6, channel: Unorm },
+    ASTC_10x6_SRGB_BLOCK => Astc { block: B10x6, channel: UnormSrgb },
+    ASTC_10x6_SFLOAT_BLOCK => Astc { block: B10x6, channel: Hdr },
+    ASTC_10x8_UNORM_BLOCK => Astc { block: B10x8, channel: Unorm },
+    ASTC_10x8_SRGB_BLOCK => Astc { block: B10x8, channel: UnormSrgb },
+    ASTC_10x8_SFLOAT_BLOCK => Astc { block: B10x8, channel: Hdr },
+    ASTC_10x10_UNORM_BLOCK => Astc { block: B10x10, channel: Unorm },
+    ASTC_10x10_SRGB_BLOCK => Astc { block: B10x10, channel: UnormSrgb },
+    ASTC_10x10_SFLOAT_BLOCK => Astc { block: B10x10, channel: Hdr },
+    ASTC_12x10_UNORM_BLOCK => Astc { block: B12x10, channel: Unorm },
+    ASTC_12x10_SRGB_BLOCK => Astc { block: B12x10, channel: UnormSrgb },
+    ASTC_12x10_SFLOAT_BLOCK => Astc { block: B12x10, channel: Hdr },
+    ASTC_12x12_UNORM_BLOCK => Astc { block: B12x12, channel: Unorm },
+    ASTC_12x12_SRGB_BLOCK => Astc { block: B12x12, channel: UnormSrgb },
+    ASTC_12x12_SFLOAT_BLOCK => Astc { block: B12x12, channel: Hdr },
+}
+
+impl Header {
+    /// Descriptor of a sampleable texture holding every level of this one, with the usages needed to upload it.
+    ///
+    /// Cubemaps become 2D textures with six layers per array layer. Fails with
+    /// [`FormatConversionError::Undefined`] for textures without a format, such as Basis Universal textures,
+    /// which must be transcoded first, and with [`FormatConversionError::LayerCountOverflow`] if the layers and
+    /// faces don't fit in a `u32`.
+    ///
+    /// ```rust
+    /// # let file = include_bytes!("../data/test_tex.ktx2");
+    /// let reader = ktx2::Reader::new(file).unwrap();
+    /// let descriptor = reader.header().wgpu_texture_descriptor(Some("test")).unwrap();
+    /// assert_eq!(descriptor.format, wgpu_types::TextureFormat::Rgba8Uint);
+    /// assert_eq!(descriptor.mip_level_count, 11);
+    /// ```
+    pub fn wgpu_texture_descriptor<'a>(
+        &self,
+        label: Option<&'a str>,
+    ) -> Result<TextureDescriptor<Option<&'a str>, &'static [TextureFormat]>, FormatConversionError> {
+        let format = TextureFormat::try_from(self.format.ok_or(FormatConversionError::Undefined)?)?;
+        let (dimension, depth_or_array_layers) = if self.pixel_depth > 0 {
+            (TextureDimension::D3, self.pixel_depth)
+        } else if self.pixel_height > 0 {
+            let layers = self.layer_count.max(1).checked_mul(self.face_count);
+            (
+                TextureDimension::D2,
+                layers.ok_or(FormatConversionError::LayerCountOverflow)?,
+            )
+        } else {
+            (TextureDimension::D1, self.layer_count.max(1))
+        };
+
+        Ok(TextureDescriptor {
+            label,
+            size: Extent3d {
+                width: self.pixel_width,
+                height: self.pixel_height.max(1),
+                depth_or_array_layers,
+            },
+            mip_level_count: self.level_count.max(1),
+            sample_count: 1,
+            dimension,
+            format,
+            usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+            view_formats: &[],
+        })
+    }
+}
+
+#[test]
+fn test_wgpu_format_round_trip() {
+    let mut count = 0;
+    for &format in Format::ALL {
+        if let Ok(texture_format) = TextureFormat::try_from(format) {
+            assert_eq!(Format::try_from(texture_format), Ok(format));
+            let (width, height) = texture_format.block_dimensions();
+            assert_eq!([width, height, 1], format.texel_block_dimensions(), "{:?}", format);
+            if let Some(size) = texture_format.block_copy_size(None) {
+                assert_eq!(size, format.block_byte_length(), "{:?}", format);
+            }
+            count += 1;
+        }
+    }
+    assert_eq!(count, 114);
+    assert_eq!(
+        TextureFormat::try_from(Format::ASTC_6x5_SFLOAT_BLOCK),
+        Ok(TextureFormat::Astc {
+            block: AstcBlock::B6x5,
+            channel: AstcChannel::Hdr
+        })
+    );
+    assert_eq!(
+        TextureFormat::try_from(Format::R8G8B8_UNORM),
+        Err(FormatConversionError::Unsupported)
+    );
+    assert_eq!(
+        Format::try_from(TextureFormat::Depth24Plus),
+        Err(FormatConversionError::Unsupported)
+    );
+}
+
+#[test]
+fn test_wgpu_texture_descriptor_layer_count_overflow() {
+    let file = crate::test_util::TEST_TEX;
+    let mut header = crate::Reader::new(file).unwrap().header();
+    header.layer_count = u32::MAX;
+    header.face_count = 6;
+    assert_eq!(
+        header.wgpu_texture_descriptor(None),
+        Err(FormatConversionError::LayerCountOverflow)
+    );
+}
@@ -0,0 +1,374 @@
+use crate::{
+    error::WriteError, level_alignment, DataFormatDescriptorHeader, Format, Header, Index, Level, LevelIndex,
+    SupercompressionScheme,
+};
+use alloc::{borrow::Cow, vec, vec::Vec};
+
+/// Container-level metadata supplied to a [`Writer`].
+///
+/// This mirrors [`Header`] without the [`Index`], which the writer computes from the sections it is given.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct WriterHeader {
+    pub format: Option<Format>,
+    pub type_size: u32,
+    pub pixel_width: u32,
+    pub pixel_height: u32,
+    pub pixel_depth: u32,
+    pub layer_count: u32,
+    pub face_count: u32,
+    pub level_count: u32,
+    pub supercompression_scheme: Option<SupercompressionScheme>,
+}
+
+/// Serializes a complete KTX2 container.
+///
+/// ```rust
+/// # let file = include_bytes!("../data/test_tex.ktx2");
+/// let reader = ktx2::Reader::new(file).unwrap();
+/// let header = reader.header();
+/// let dfd_start = header.index.dfd_byte_offset as usize + 4;
+/// let dfd_end = (header.index.dfd_byte_offset + header.index.dfd_byte_length) as usize;
+///
+/// let mut writer = ktx2::Writer::new(ktx2::WriterHeader {
+///     format: header.format,
+///     type_size: header.type_size,
+///     pixel_width: header.pixel_width,
+///     pixel_height: header.pixel_height,
+///     pixel_depth: header.pixel_depth,
+///     layer_count: header.layer_count,
+///     face_count: header.face_count,
+///     level_count: header.level_count,
+///     supercompression_scheme: header.supercompression_scheme,
+/// });
+/// writer.data_format_descriptors(&file[dfd_start..dfd_end]);
+/// for (key, value) in reader.key_value_data() {
+///     writer.key_value(key, value);
+/// }
+/// for level in reader.levels() {
+///     writer.level(level);
+/// }
+///
+/// let bytes = writer.write().unwrap();
+/// assert_eq!(&bytes[..], &file[..]);
+/// ```
+pub struct Writer<'a> {
+    header: WriterHeader,
+    dfd: &'a [u8],
+    key_value_data: Vec<(&'a str, Cow<'a, [u8]>)>,
+    supercompression_global_data: &'a [u8],
+    levels: Vec<Level<'a>>,
+    compress_levels: bool,
+}
+
+impl<'a> Writer<'a> {
+    /// Create a writer for a texture described by `header`.
+    pub fn new(header: WriterHeader) -> Self {
+        Self {
+            header,
+            dfd: &[],
+            key_value_data: Vec::new(),
+            supercompression_global_data: &[],
+            levels: Vec::new(),
+            compress_levels: false,
+        }
+    }
+
+    /// Set the data format descriptor blocks.
+    ///
+    /// `dfd` is the concatenation of descriptor blocks, each starting with its own block header.
+    /// The leading `dfdTotalSize` field is written by the writer.
+    pub fn data_format_descriptors(&mut self, dfd: &'a [u8]) -> &mut Self {
+        self.dfd = dfd;
+        self
+    }
+
+    /// Add a key-value pair. Pairs are sorted by key when written.
+    pub fn key_value(&mut self, key: &'a str, value: &'a [u8]) -> &mut Self {
+        self.key_value_data.push((key, Cow::Borrowed(value)));
+        self
+    }
+
+    /// Add a key-value pair with an owned value.
+    pub(crate) fn key_value_owned(&mut self, key: &'a str, value: Vec<u8>) -> &mut Self {
+        self.key_value_data.push((key, Cow::Owned(value)));
+        self
+    }
+
+    /// Set the supercompression global data.
+    pub fn supercompression_global_data(&mut self, data: &'a [u8]) -> &mut Self {
+        self.supercompression_global_data = data;
+        self
+    }
+
+    /// Add the next mip level. Levels must be added starting with the base level.
+    pub fn level(&mut self, level: Level<'a>) -> &mut Self {
+        self.levels.push(level);
+        self
+    }
+
+    /// Supercompress levels while writing, using the header's supercompression scheme.
+    ///
+    /// Levels are then given uncompressed, so their length must match [`Level::uncompressed_byte_length`].
+    /// Only [`SupercompressionScheme::ZLIB`] is supported.
+    #[cfg(feature = "zlib")]
+    pub fn compress_levels(&mut self) -> &mut Self {
+        self.compress_levels = true;
+        self
+    }
+
+    /// Serialize the container.
+    pub fn write(&self) -> Result<Vec<u8>, WriteError> {
+        let level_count = self.header.level_count.max(1) as usize;
+        if self.levels.len() != level_count {
+            return Err(WriteError::LevelCountMismatch);
+        }
+        if self.dfd.is_empty() || !self.dfd.len().is_multiple_of(4) {
+            return Err(WriteError::InvalidDataFormatDescriptor);
+        }
+        if (self.header.supercompression_scheme.is_none() || self.compress_levels)
+            && self
+                .levels
+                .iter()
+                .any(|level| level.uncompressed_byte_length != level.data.len() as u64)
+        {
+            return Err(WriteError::UncompressedLengthMismatch);
+        }
+
+        let levels = self.level_data()?;
+        let key_value_data = self.key_value_data_bytes()?;
+        // Supercompressed data has no planes
+        let dfd = if self.compress_levels {
+            Cow::Owned(zero_bytes_planes(self.dfd))
+        } else {
+            Cow::Borrowed(self.dfd)
+        };
+
+        let dfd_byte_offset = Header::LENGTH + level_count * LevelIndex::LENGTH;
+        let dfd_byte_length = 4 + dfd.len();
+        let mut offset = dfd_byte_offset + dfd_byte_length;
+
+        let (kvd_byte_offset, kvd_byte_length) = if key_value_data.is_empty() {
+            (0, 0)
+        } else {
+            let kvd_byte_offset = offset;
+            offset += key_value_data.len();
+            (kvd_byte_offset, key_value_data.len())
+        };
+
+        let (sgd_byte_offset, sgd_byte_length) = if self.supercompression_global_data.is_empty() {
+            (0, 0)
+        } else {
+            offset = align(offset, 8);
+            let sgd_byte_offset = offset;
+            offset += self.supercompression_global_data.len();
+            (sgd_byte_offset, self.supercompression_global_data.len())
+        };
+
+        // Levels are stored smallest first
+        let level_alignment = level_alignment(self.header.supercompression_scheme, &dfd);
+        let mut level_index = vec![
+            LevelIndex {
+                byte_offset: 0,
+                byte_length: 0,
+                uncompressed_byte_length: 0,
+            };
+            level_count
+        ];
+        for (index, (level, data)) in self.levels.iter().zip(&levels).enumerate().rev() {
+            offset = align(offset, level_alignment);
+            level_index[index] = LevelIndex {
+                byte_offset: offset as u64,
+                byte_length: data.len() as u64,
+                uncompressed_byte_length: level.uncompressed_byte_length,
+            };
+            offset += data.len();
+        }
+
+        if dfd_byte_offset + dfd_byte_length > u32::MAX as usize
+            || kvd_byte_offset + kvd_byte_length > u32::MAX as usize
+        {
+            return Err(WriteError::Overflow);
+        }
+
+        let header = Header {
+            format: self.header.format,
+            type_size: self.header.type_size,
+            pixel_width: self.header.pixel_width,
+            pixel_height: self.header.pixel_height,
+            pixel_depth: self.header.pixel_depth,
+            layer_count: self.header.layer_count,
+            face_count: self.header.face_count,
+            level_count: self.header.level_count,
+            supercompression_scheme: self.header.supercompression_scheme,
+            index: Index {
+                dfd_byte_offset: dfd_byte_offset as u32,
+                dfd_byte_length: dfd_byte_length as u32,
+                kvd_byte_offset: kvd_byte_offset as u32,
+                kvd_byte_length: kvd_byte_length as u32,
+                sgd_byte_offset: sgd_byte_offset as u64,
+                sgd_byte_length: sgd_byte_length as u64,
+            },
+        };
+
+        let mut bytes = Vec::with_capacity(offset);
+        bytes.extend_from_slice(&header.as_bytes());
+        for level in &level_index {
+            bytes.extend_from_slice(&level.as_bytes());
+        }
+        bytes.extend_from_slice(&(dfd_byte_length as u32).to_le_bytes());
+        bytes.extend_from_slice(&dfd);
+        bytes.extend_from_slice(&key_value_data);
+        if sgd_byte_length != 0 {
+            bytes.resize(sgd_byte_offset, 0);
+            bytes.extend_from_slice(self.supercompression_global_data);
+        }
+        for (data, index) in levels.iter().zip(&level_index).rev() {
+            bytes.resize(index.byte_offset as usize, 0);
+            bytes.extend_from_slice(data);
+        }
+
+        Ok(bytes)
+    }
+
+    fn level_data(&self) -> Result<Vec<Cow<'a, [u8]>>, WriteError> {
+        if !self.compress_levels {
+            return Ok(self.levels.iter().map(|level| Cow::Borrowed(level.data)).collect());
+        }
+
+        #[cfg(feature = "zlib")]
+        if let Some(scheme) = self.header.supercompression_scheme {
+            return self
+                .levels
+                .iter()
+                .map(|level| {
+                    crate::supercompression::compress(level.data, scheme)
+                        .map(Cow::Owned)
+                        .ok_or(WriteError::UnsupportedSupercompressionScheme)
+                })
+                .collect();
+        }
+
+        Err(WriteError::UnsupportedSupercompressionScheme)
+    }
+
+    fn key_value_data_bytes(&self) -> Result<Vec<u8>, WriteError> {
+        let mut pairs = self.key_value_data.clone();
+        pairs.sort_by(|(a, _), (b, _)| a.as_bytes().cmp(b.as_bytes()));
+
+        let mut bytes = Vec::new();
+        for (i, (key, value)) in pairs.iter().enumerate() {
+            if key.is_empty() || key.contains('\0') {
+                return Err(WriteError::InvalidKey);
+            }
+            if i > 0 && pairs[i - 1].0 == *key {
+                return Err(WriteError::DuplicateKey);
+            }
+
+            let length = key.len() + 1 + value.len();
+            bytes.extend_from_slice(&(length as u32).to_le_bytes());
+            bytes.extend_from_slice(key.as_bytes());
+            bytes.push(0);
+            bytes.extend_from_slice(value);
+            bytes.resize(align(bytes.len(), 4), 0);
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// Copy of `dfd` with the `bytesPlane` fields of its basic descriptor blocks zeroed.
+fn zero_bytes_planes(dfd: &[u8]) -> Vec<u8> {
+    let mut dfd = dfd.to_vec();
+    let mut offset = 0;
+    while let Some(Ok((header, block_size))) = dfd
+        .get(offset..offset + DataFormatDescriptorHeader::LENGTH)
+        .map(DataFormatDescriptorHeader::parse)
+    {
+        if block_size == 0 {
+            break;
+        }
+        if header == DataFormatDescriptorHeader::BASIC {
+            // bytesPlane0 to bytesPlane7 follow the model, primaries, transfer function, flags and block dimensions
+            if let Some(bytes_planes) = dfd.get_mut(offset + 16..offset + 24) {
+                bytes_planes.fill(0);
+            }
+        }
+        offset += block_size;
+    }
+    dfd
+}
+
+fn align(offset: usize, alignment: usize) -> usize {
+    offset.div_ceil(alignment) * alignment
+}
+
+#[test]
+fn test_write_sorts_key_value_data_and_aligns_sections() {
+    use crate::test_util::{test_tex_dfd, writer_header};
+
+    let mut writer = Writer::new(WriterHeader {
+        supercompression_scheme: Some(SupercompressionScheme::Zstandard),
+        ..writer_header(None, 4)
+    });
+    writer
+        .data_format_descriptors(test_tex_dfd())
+        .key_value("b", b"2\0")
+        .key_value("a", b"1\0")
+        .supercompression_global_data(&[1, 2, 3])
+        .level(Level {
+            data: &[7; 5],
+            uncompressed_byte_length: 64,
+        });
+    let bytes = writer.write().unwrap();
+
+    let reader = crate::Reader::new(&bytes[..]).unwrap();
+    let header = reader.header();
+    assert_eq!(header.index.sgd_byte_offset % 8, 0);
+    assert_eq!(reader.supercompression_global_data(), &[1, 2, 3]);
+    assert_eq!(
+        reader.key_value_data().collect::<Vec<_>>(),
+        [("a", &b"1\0"[..]), ("b", &b"2\0"[..])]
+    );
+    let levels = reader.levels().collect::<Vec<_>>();
+    assert_eq!(levels[0].data, &[7; 5]);
+    assert_eq!(levels[0].uncompressed_byte_length, 64);
+
+    writer.key_value("a", b"");
+    assert!(matches!(writer.write(), Err(WriteError::DuplicateKey)));
+}
+
+#[cfg(feature = "zlib")]
+#[test]
+fn test_write_compresses_zlib_levels() {
+    use crate::test_util::{test_tex_dfd, TEST_TEX};
+
+    let reader = crate::Reader::new(TEST_TEX).unwrap();
+    let header = reader.header();
+
+    let mut writer = Writer::new(WriterHeader {
+        format: header.format,
+        type_size: header.type_size,
+        pixel_width: header.pixel_width,
+        pixel_height: header.pixel_height,
+        pixel_depth: header.pixel_depth,
+        layer_count: header.layer_count,
+        face_count: header.face_count,
+        level_count: header.level_count,
+        supercompression_scheme: Some(SupercompressionScheme::ZLIB),
+    });
+    writer.data_format_descriptors(test_tex_dfd()).compress_levels();
+    for level in reader.levels() {
+        writer.level(level);
+    }
+    let bytes = writer.write().unwrap();
+
+    let compressed = crate::Reader::new(&bytes[..]).unwrap();
+    assert_eq!(compressed.levels().next().unwrap().data[0], 0x78);
+    let decoded = compressed.decoded_levels().collect::<Result<Vec<_>, _>>().unwrap();
+    assert!(decoded.iter().zip(reader.levels()).all(|(a, b)| a[..] == *b.data));
+
+    let dfd = compressed.data_format_descriptors().next().unwrap();
+    let dfd = crate::BasicDataFormatDescriptor::parse(dfd.data).unwrap();
+    assert_eq!(dfd.header.bytes_planes, [0; 8]);
+    assert_eq!(compressed.validate(), [crate::ValidationIssue::MissingWriter]);
+}